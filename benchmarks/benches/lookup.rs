@@ -1,4 +1,6 @@
-use benchmarks::{PHF_MAPS, QUICKPHF_MAPS, QUICKPHF_RAW_MAPS, SIZES};
+use benchmarks::{
+    PHF_MAPS, QUICKPHF_BLOB_MAPS, QUICKPHF_MAPS, QUICKPHF_RAW_MAPS, QUICKPHF_SOA_MAPS, SIZES,
+};
 
 const BATCH_SIZE: usize = 1000;
 const SEED: u64 = 42;
@@ -54,6 +56,40 @@ fn quickphf_raw_map<const S: usize>(bencher: divan::Bencher) {
         })
 }
 
+#[divan::bench(consts = SIZES, sample_size = 1)]
+fn quickphf_soa_map<const S: usize>(bencher: divan::Bencher) {
+    let mut rng = fastrand::Rng::with_seed(SEED);
+
+    let index = SIZES.iter().position(|&s| s == S).unwrap();
+    let map = &QUICKPHF_SOA_MAPS[index];
+    let keys = map.keys().copied().collect::<Vec<_>>();
+
+    bencher
+        .with_inputs(|| (0..BATCH_SIZE).map(|_| keys[rng.usize(0..S)]))
+        .bench_local_refs(|queries| {
+            for query in queries {
+                divan::black_box(map.get(&query).unwrap());
+            }
+        })
+}
+
+#[divan::bench(consts = SIZES, sample_size = 1)]
+fn quickphf_blob_map<const S: usize>(bencher: divan::Bencher) {
+    let mut rng = fastrand::Rng::with_seed(SEED);
+
+    let index = SIZES.iter().position(|&s| s == S).unwrap();
+    let map = &QUICKPHF_BLOB_MAPS[index];
+    let keys = map.iter().map(|(key, _)| key).collect::<Vec<_>>();
+
+    bencher
+        .with_inputs(|| (0..BATCH_SIZE).map(|_| keys[rng.usize(0..S)]))
+        .bench_local_refs(|queries| {
+            for query in queries {
+                divan::black_box(map.get(&query).unwrap());
+            }
+        })
+}
+
 fn main() {
     divan::main();
 }
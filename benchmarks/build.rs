@@ -1,6 +1,6 @@
 use std::{env, fs, iter::repeat_with, path::Path};
 
-use quickphf_codegen::{build_map, build_raw_map};
+use quickphf_codegen::{build_blob_map, build_map, build_raw_map, build_soa_map};
 
 const SEED: u64 = 42;
 static SIZES: &[usize] = &[
@@ -46,5 +46,23 @@ fn main() {
         let file_name = format!("quickphf_raw_map_{}.rs", i);
         let dest_path = Path::new(&out_dir).join(file_name);
         fs::write(&dest_path, quickphf_code).unwrap();
+
+        // Generate quickphf static struct-of-arrays maps
+        let quickphf_code = build_soa_map(&keys, &values).to_string();
+
+        let file_name = format!("quickphf_soa_map_{}.rs", i);
+        let dest_path = Path::new(&out_dir).join(file_name);
+        fs::write(&dest_path, quickphf_code).unwrap();
+
+        // Generate quickphf static blob maps
+        let blob_writer = build_blob_map(&keys, &values);
+
+        let blob_path = Path::new(&out_dir).join(format!("quickphf_blob_map_{}.bin", i));
+        fs::write(&blob_path, blob_writer.blob()).unwrap();
+        let quickphf_code = blob_writer.code(blob_path.to_str().unwrap()).to_string();
+
+        let file_name = format!("quickphf_blob_map_{}.rs", i);
+        let dest_path = Path::new(&out_dir).join(file_name);
+        fs::write(&dest_path, quickphf_code).unwrap();
     }
 }
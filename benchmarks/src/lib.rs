@@ -28,6 +28,34 @@ pub static QUICKPHF_RAW_MAPS: [::quickphf::RawPhfMap<u64, u64>; 11] = [
     include!(concat!(env!("OUT_DIR"), "/quickphf_raw_map_10.rs")),
 ];
 
+pub static QUICKPHF_SOA_MAPS: [::quickphf::SoaPhfMap<u64, u64>; 11] = [
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_0.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_1.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_2.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_3.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_4.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_5.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_6.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_7.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_8.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_9.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_soa_map_10.rs")),
+];
+
+pub static QUICKPHF_BLOB_MAPS: [::quickphf::BlobPhfMap<u64, u64>; 11] = [
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_0.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_1.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_2.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_3.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_4.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_5.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_6.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_7.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_8.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_9.rs")),
+    include!(concat!(env!("OUT_DIR"), "/quickphf_blob_map_10.rs")),
+];
+
 pub static PHF_MAPS: [::phf::Map<u64, u64>; 11] = [
     include!(concat!(env!("OUT_DIR"), "/phf_map_0.rs")),
     include!(concat!(env!("OUT_DIR"), "/phf_map_1.rs")),
@@ -0,0 +1,280 @@
+//! An immutable hash table constructed at compile time with perfect hashing,
+//! in which several alias keys can refer to the same canonical entry.
+
+use core::hash::Hash;
+
+use crate::equivalent::Equivalent;
+use crate::map::Iter;
+use crate::RawPhfMap;
+
+/// An immutable hash table constructed at compile time with perfect hashing,
+/// in which several alias keys can refer to the same canonical entry.
+///
+/// Each canonical entry is stored once, while both its canonical key and its
+/// aliases map to it, so that lookups by any of them can report the canonical
+/// key.
+#[derive(Debug)]
+pub struct AliasPhfMap<K: 'static, V: 'static> {
+    raw_map: RawPhfMap<K, (K, u32)>,
+    entries: &'static [(K, V)],
+}
+
+impl<K, V> AliasPhfMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        keys: &'static [(K, u32)],
+        free: &'static [u32],
+        entries: &'static [(K, V)],
+    ) -> AliasPhfMap<K, V> {
+        AliasPhfMap {
+            raw_map: RawPhfMap::new(seed, pilots_table, keys, free),
+            entries,
+        }
+    }
+
+    /// Returns the canonical entry of the given key, which may be either a
+    /// canonical key or an alias, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(
+    ///     TIMEZONES.get_canonical("Asia/Calcutta"),
+    ///     Some((&"Asia/Kolkata", &330))
+    /// );
+    /// assert_eq!(TIMEZONES.get_canonical("Etc/UTC"), Some((&"Etc/UTC", &0)));
+    /// assert_eq!(TIMEZONES.get_canonical("Mars/Olympus"), None);
+    /// ```
+    pub fn get_canonical<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.position(key).map(|pos| {
+            let entry = &self.entries[pos];
+            (&entry.0, &entry.1)
+        })
+    }
+
+    /// Returns a reference to the value corresponding to the key, which may
+    /// be either a canonical key or an alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(TIMEZONES.get("US/Eastern"), Some(&-300));
+    /// assert_eq!(TIMEZONES.get("America/New_York"), Some(&-300));
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_canonical(key).map(|e| e.1)
+    }
+
+    /// Returns the canonical key corresponding to the key, which may be
+    /// either a canonical key or an alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(TIMEZONES.canonical_key("Europe/Kiev"), Some(&"Europe/Kyiv"));
+    /// ```
+    pub fn canonical_key<Q>(&self, key: &Q) -> Option<&K>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_canonical(key).map(|e| e.0)
+    }
+
+    /// Returns `true` if the map contains the given key, either as a
+    /// canonical key or as an alias.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(TIMEZONES.contains_key("UTC"));
+    /// assert!(!TIMEZONES.contains_key("GMT"));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    /// Returns `true` if the given key is an alias of another key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(TIMEZONES.is_alias("Etc/Zulu"));
+    /// assert!(!TIMEZONES.is_alias("Etc/UTC"));
+    /// assert!(!TIMEZONES.is_alias("Mars/Olympus"));
+    /// ```
+    pub fn is_alias<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.canonical_key(key)
+            .map_or(false, |canonical| !key.equivalent(canonical))
+    }
+
+    /// Returns the number of keys in the map, including aliases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(TIMEZONES.len(), 9);
+    /// assert_eq!(EMPTY_ALIAS_MAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.raw_map.len()
+    }
+
+    /// Returns the number of canonical entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(TIMEZONES.canonical_len(), 4);
+    /// ```
+    pub const fn canonical_len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map does not contain any keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!TIMEZONES.is_empty());
+    /// assert!(EMPTY_ALIAS_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.raw_map.is_empty()
+    }
+
+    /// An iterator visiting the canonical entries in the order in which they
+    /// were supplied to `quickphf_codegen`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let zones = TIMEZONES.iter().map(|e| e.0).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     &zones,
+    ///     &["Europe/Kyiv", "Asia/Kolkata", "America/New_York", "Etc/UTC"]
+    /// );
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.entries)
+    }
+
+    /// An iterator visiting all keys, including aliases, in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let aliases = TIMEZONES.keys().filter(|k| TIMEZONES.is_alias(*k)).count();
+    ///
+    /// assert_eq!(aliases, 5);
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K> {
+        Keys {
+            iter: self.raw_map.as_slice().iter(),
+        }
+    }
+
+    /// Returns the position of the canonical entry of the given key.
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let entry = self.raw_map.value_of(key);
+        if key.equivalent(&entry.0) {
+            Some(entry.1 as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a AliasPhfMap<K, V> {
+    type Item = &'a (K, V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+#[derive(Clone)]
+/// An iterator over the keys of an `AliasPhfMap`, including aliases.
+pub struct Keys<'a, K: 'static> {
+    iter: core::slice::Iter<'a, (K, u32)>,
+}
+
+impl<'a, K> Iterator for Keys<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|e| &e.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K> ExactSizeIterator for Keys<'a, K> {}
+
+impl<'a, K> core::iter::FusedIterator for Keys<'a, K> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_ALIAS_MAP, TIMEZONES};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_ALIAS_MAP.get("Kettricken"), None);
+        assert!(!EMPTY_ALIAS_MAP.is_alias("Kettricken"));
+    }
+
+    #[test]
+    fn test_every_key_reaches_canonical_entry() {
+        for key in TIMEZONES.keys() {
+            let (canonical, value) = TIMEZONES.get_canonical(key).unwrap();
+            assert_eq!(TIMEZONES.get_canonical(canonical), Some((canonical, value)));
+            assert!(!TIMEZONES.is_alias(canonical));
+        }
+    }
+}
@@ -0,0 +1,442 @@
+//! An immutable hash table constructed at compile time with perfect hashing,
+//! whose string or byte string keys are stored in a single arena.
+
+use core::hash::Hash;
+
+use crate::RawPhfMap;
+
+/// The position and length of a string within the arena of an
+/// [`ArenaPhfMap`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Span {
+    start: u32,
+    len: u32,
+}
+
+impl Span {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate spans—users should never directly write calls to it.
+    pub const fn new(start: u32, len: u32) -> Span {
+        Span { start, len }
+    }
+
+    /// Returns the position of the start of the string within the arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let span = *CAPITALS.get("Peru").unwrap();
+    /// assert_eq!(&CAPITALS.arena()[span.start() as usize..][..4], "Lima");
+    /// ```
+    pub const fn start(self) -> u32 {
+        self.start
+    }
+
+    /// Returns the length of the string in bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(CAPITALS.get("Peru").unwrap().len(), 4);
+    /// ```
+    pub const fn len(self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if the string is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!CAPITALS.get("Peru").unwrap().is_empty());
+    /// ```
+    pub const fn is_empty(self) -> bool {
+        self.len == 0
+    }
+}
+
+/// A type of string which can serve as the arena of an [`ArenaPhfMap`].
+///
+/// It is implemented for `str` and `[u8]`.
+pub trait ArenaSlice: Hash + Eq {
+    /// Returns the part of the arena covered by the given span.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the span does not lie within the arena.
+    fn slice(&self, span: Span) -> &Self;
+}
+
+impl ArenaSlice for str {
+    fn slice(&self, span: Span) -> &str {
+        &self[span.start as usize..(span.start + span.len) as usize]
+    }
+}
+
+impl ArenaSlice for [u8] {
+    fn slice(&self, span: Span) -> &[u8] {
+        &self[span.start as usize..(span.start + span.len) as usize]
+    }
+}
+
+/// An immutable hash table constructed at compile time with perfect hashing,
+/// whose string or byte string keys are stored in a single arena.
+///
+/// Instead of a separate `&'static str` or `&'static [u8]` for each key, the
+/// keys are concatenated into one arena, and each entry holds the [`Span`] of
+/// its key within it. This halves the size of the entries of string keys,
+/// and avoids emitting a separate literal for each of them.
+///
+/// String values can be stored in the same arena, in which case the values
+/// of the map are their spans, and can be looked up with
+/// [`get_resolved`](ArenaPhfMap::get_resolved).
+#[derive(Debug)]
+pub struct ArenaPhfMap<K: ?Sized + 'static, V: 'static> {
+    raw_map: RawPhfMap<&'static K, (Span, V)>,
+    arena: &'static K,
+}
+
+impl<K: ?Sized, V> ArenaPhfMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        entries: &'static [(Span, V)],
+        free: &'static [u32],
+        arena: &'static K,
+    ) -> ArenaPhfMap<K, V> {
+        ArenaPhfMap {
+            raw_map: RawPhfMap::new(seed, pilots_table, entries, free),
+            arena,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(CAPITALS.len(), 6);
+    /// assert_eq!(EMPTY_ARENA_MAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.raw_map.len()
+    }
+
+    /// Returns `true` if the map does not contain any entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!CAPITALS.is_empty());
+    /// assert!(EMPTY_ARENA_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.raw_map.is_empty()
+    }
+
+    /// Returns the arena holding the keys of the map, and possibly its
+    /// values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(FILE_SIGNATURES.arena().starts_with(b"%PDF"));
+    /// ```
+    pub const fn arena(&self) -> &'static K {
+        self.arena
+    }
+}
+
+impl<K: ArenaSlice + ?Sized, V> ArenaPhfMap<K, V> {
+    /// Returns the key stored in the map and a reference to its value, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let (signature, mime) = FILE_SIGNATURES.get_key_value(b"%PDF").unwrap();
+    ///
+    /// assert_eq!(signature, b"%PDF");
+    /// assert_eq!(*mime, "application/pdf");
+    /// ```
+    pub fn get_key_value(&self, key: &K) -> Option<(&'static K, &V)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let entry = &self.raw_map.as_slice()[self.raw_map.slot_of(key)];
+        let stored = self.arena.slice(entry.0);
+        if stored == key {
+            Some((stored, &entry.1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(FILE_SIGNATURES.get(b"GIF8"), Some(&"image/gif"));
+    /// assert_eq!(FILE_SIGNATURES.get(b"RIFF"), None);
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(CAPITALS.contains_key("Chile"));
+    /// assert!(!CAPITALS.contains_key("Santiago"));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Returns the part of the arena covered by the given span.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the span does not lie within the arena.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let span = *CAPITALS.get("Chile").unwrap();
+    /// assert_eq!(CAPITALS.resolve(span), "Santiago");
+    /// ```
+    pub fn resolve(&self, span: Span) -> &'static K {
+        self.arena.slice(span)
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut images = FILE_SIGNATURES
+    ///     .iter()
+    ///     .filter(|(_, mime)| mime.starts_with("image/"))
+    ///     .map(|(_, &mime)| mime)
+    ///     .collect::<Vec<_>>();
+    /// images.sort();
+    ///
+    /// assert_eq!(&images, &["image/gif", "image/png"]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            entries: self.raw_map.as_slice().iter(),
+            arena: self.arena,
+        }
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut countries = CAPITALS.keys().collect::<Vec<_>>();
+    /// countries.sort();
+    ///
+    /// assert_eq!(
+    ///     &countries,
+    ///     &["Argentina", "Bolivia", "Brazil", "Chile", "Colombia", "Peru"]
+    /// );
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let applications = FILE_SIGNATURES
+    ///     .values()
+    ///     .filter(|mime| mime.starts_with("application/"))
+    ///     .count();
+    ///
+    /// assert_eq!(applications, 3);
+    /// ```
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+}
+
+impl<K: ArenaSlice + ?Sized> ArenaPhfMap<K, Span> {
+    /// Returns the value corresponding to the key, for maps whose values are
+    /// stored in the arena alongside their keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(CAPITALS.get_resolved("Bolivia"), Some("Sucre"));
+    /// assert_eq!(CAPITALS.get_resolved("Ecuador"), None);
+    /// ```
+    pub fn get_resolved(&self, key: &K) -> Option<&'static K> {
+        self.get(key).map(|&span| self.resolve(span))
+    }
+}
+
+impl<'a, K: ArenaSlice + ?Sized, V> IntoIterator for &'a ArenaPhfMap<K, V> {
+    type Item = (&'static K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of an `ArenaPhfMap`.
+pub struct Iter<'a, K: ?Sized + 'static, V: 'static> {
+    entries: core::slice::Iter<'a, (Span, V)>,
+    arena: &'static K,
+}
+
+impl<'a, K: ?Sized, V> Clone for Iter<'a, K, V> {
+    fn clone(&self) -> Self {
+        Iter {
+            entries: self.entries.clone(),
+            arena: self.arena,
+        }
+    }
+}
+
+impl<'a, K: ArenaSlice + ?Sized, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'static K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let arena = self.arena;
+        self.entries
+            .next()
+            .map(|entry| (arena.slice(entry.0), &entry.1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<'a, K: ArenaSlice + ?Sized, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K: ArenaSlice + ?Sized, V> core::iter::FusedIterator for Iter<'a, K, V> {}
+
+/// An iterator over the keys of an `ArenaPhfMap`.
+pub struct Keys<'a, K: ?Sized + 'static, V: 'static> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K: ?Sized, V> Clone for Keys<'a, K, V> {
+    fn clone(&self) -> Self {
+        Keys {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, K: ArenaSlice + ?Sized, V> Iterator for Keys<'a, K, V> {
+    type Item = &'static K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|e| e.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: ArenaSlice + ?Sized, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+impl<'a, K: ArenaSlice + ?Sized, V> core::iter::FusedIterator for Keys<'a, K, V> {}
+
+/// An iterator over the values of an `ArenaPhfMap`.
+pub struct Values<'a, K: ?Sized + 'static, V: 'static> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K: ?Sized, V> Clone for Values<'a, K, V> {
+    fn clone(&self) -> Self {
+        Values {
+            iter: self.iter.clone(),
+        }
+    }
+}
+
+impl<'a, K: ArenaSlice + ?Sized, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|e| e.1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: ArenaSlice + ?Sized, V> ExactSizeIterator for Values<'a, K, V> {}
+
+impl<'a, K: ArenaSlice + ?Sized, V> core::iter::FusedIterator for Values<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{CAPITALS, EMPTY_ARENA_MAP, FILE_SIGNATURES};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_ARENA_MAP.get("Burrich"), None);
+        assert!(EMPTY_ARENA_MAP.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for (country, &capital) in &CAPITALS {
+            assert_eq!(
+                CAPITALS.get_resolved(country),
+                Some(CAPITALS.resolve(capital))
+            );
+        }
+
+        for (signature, mime) in &FILE_SIGNATURES {
+            assert_eq!(FILE_SIGNATURES.get(signature), Some(mime));
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! An immutable bidirectional map constructed at compile time with perfect
+//! hashing.
+
+use core::hash::Hash;
+
+use crate::equivalent::Equivalent;
+use crate::map::Iter;
+use crate::{PhfMap, RawPhfMap};
+
+/// An immutable bidirectional map constructed at compile time with perfect
+/// hashing.
+///
+/// Both the left and the right values of a `PhfBiMap` are unique, so that
+/// each side can be used to look up the other. The pairs are stored once,
+/// with a separate perfect hash function for each side.
+#[derive(Debug)]
+pub struct PhfBiMap<L: 'static, R: 'static> {
+    left: PhfMap<L, R>,
+    right: RawPhfMap<R, u32>,
+}
+
+impl<L, R> PhfBiMap<L, R> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    #[allow(clippy::too_many_arguments)]
+    pub const fn new(
+        left_seed: u64,
+        left_pilots_table: &'static [u16],
+        entries: &'static [(L, R)],
+        left_free: &'static [u32],
+        right_seed: u64,
+        right_pilots_table: &'static [u16],
+        right_indices: &'static [u32],
+        right_free: &'static [u32],
+    ) -> PhfBiMap<L, R> {
+        PhfBiMap {
+            left: PhfMap::new(left_seed, left_pilots_table, entries, left_free),
+            right: RawPhfMap::new(right_seed, right_pilots_table, right_indices, right_free),
+        }
+    }
+
+    /// Returns a reference to the right value corresponding to the given left
+    /// value, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(HTTP_STATUSES.get_by_left(&404), Some(&"Not Found"));
+    /// assert_eq!(HTTP_STATUSES.get_by_left(&418), None);
+    /// ```
+    pub fn get_by_left<Q>(&self, left: &Q) -> Option<&R>
+    where
+        Q: Hash + Equivalent<L> + ?Sized,
+    {
+        self.left.get(left)
+    }
+
+    /// Returns a reference to the left value corresponding to the given right
+    /// value, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(HTTP_STATUSES.get_by_right("Created"), Some(&201));
+    /// assert_eq!(HTTP_STATUSES.get_by_right("I'm a teapot"), None);
+    /// ```
+    pub fn get_by_right<Q>(&self, right: &Q) -> Option<&L>
+    where
+        Q: Hash + Equivalent<R> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let entry = &self.left.entries()[*self.right.value_of(right) as usize];
+        if right.equivalent(&entry.1) {
+            Some(&entry.0)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the map contains the given left value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(HTTP_STATUSES.contains_left(&200));
+    /// assert!(!HTTP_STATUSES.contains_left(&302));
+    /// ```
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        Q: Hash + Equivalent<L> + ?Sized,
+    {
+        self.get_by_left(left).is_some()
+    }
+
+    /// Returns `true` if the map contains the given right value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(HTTP_STATUSES.contains_right("OK"));
+    /// assert!(!HTTP_STATUSES.contains_right("Gone"));
+    /// ```
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        Q: Hash + Equivalent<R> + ?Sized,
+    {
+        self.get_by_right(right).is_some()
+    }
+
+    /// Returns the number of pairs in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(HTTP_STATUSES.len(), 5);
+    /// assert_eq!(EMPTY_BIMAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    /// Returns `true` if the map does not contain any pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!HTTP_STATUSES.is_empty());
+    /// assert!(EMPTY_BIMAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    /// Returns the map from left to right values as a [`PhfMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let reasons = HTTP_STATUSES.left_map();
+    /// assert_eq!(reasons.get(&500), Some(&"Internal Server Error"));
+    /// ```
+    pub const fn left_map(&self) -> &PhfMap<L, R> {
+        &self.left
+    }
+
+    /// An iterator visiting all pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut codes = HTTP_STATUSES.iter().map(|e| e.0).collect::<Vec<_>>();
+    /// codes.sort();
+    ///
+    /// assert_eq!(&codes, &[200, 201, 301, 404, 500]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, L, R> {
+        self.left.iter()
+    }
+}
+
+impl<'a, L, R> IntoIterator for &'a PhfBiMap<L, R> {
+    type Item = &'a (L, R);
+    type IntoIter = Iter<'a, L, R>;
+
+    fn into_iter(self) -> Iter<'a, L, R> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_BIMAP, HTTP_STATUSES};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_BIMAP.get_by_left("Patience"), None);
+        assert_eq!(EMPTY_BIMAP.get_by_right(&1), None);
+    }
+
+    #[test]
+    fn test_both_directions() {
+        for (code, reason) in &HTTP_STATUSES {
+            assert_eq!(HTTP_STATUSES.get_by_left(code), Some(reason));
+            assert_eq!(HTTP_STATUSES.get_by_right(reason), Some(code));
+        }
+    }
+}
@@ -0,0 +1,344 @@
+//! An immutable hash table constructed at compile time with perfect hashing,
+//! whose tables are stored in a binary blob rather than in Rust literals.
+
+use core::convert::TryInto;
+use core::hash::Hash;
+use core::marker::PhantomData;
+
+use quickdiv::DivisorU64;
+
+use crate::equivalent::Equivalent;
+use crate::shared::{get_bucket, get_index, hash_key, hash_pilot_value};
+
+/// A type whose values have an encoding of a fixed number of bytes, and can
+/// thus be stored in a [`BlobPhfMap`].
+///
+/// It is implemented for `bool`, the integer types and byte arrays, all of
+/// which are encoded in little-endian byte order.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::blob_map::FixedBytes;
+///
+/// #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+/// struct Rgb(u8, u8, u8);
+///
+/// impl FixedBytes for Rgb {
+///     const SIZE: usize = 3;
+///
+///     fn read(bytes: &[u8]) -> Rgb {
+///         Rgb(bytes[0], bytes[1], bytes[2])
+///     }
+///
+///     fn write(&self, bytes: &mut [u8]) {
+///         bytes.copy_from_slice(&[self.0, self.1, self.2]);
+///     }
+/// }
+/// ```
+pub trait FixedBytes: Sized {
+    /// The number of bytes in the encoding of a value.
+    const SIZE: usize;
+
+    /// Decodes a value from `bytes`, which are always exactly
+    /// [`SIZE`](FixedBytes::SIZE) bytes written by a call to
+    /// [`write`](FixedBytes::write).
+    fn read(bytes: &[u8]) -> Self;
+
+    /// Encodes the value into `bytes`, which are exactly
+    /// [`SIZE`](FixedBytes::SIZE) bytes long.
+    fn write(&self, bytes: &mut [u8]);
+}
+
+impl FixedBytes for bool {
+    const SIZE: usize = 1;
+
+    fn read(bytes: &[u8]) -> bool {
+        bytes[0] != 0
+    }
+
+    fn write(&self, bytes: &mut [u8]) {
+        bytes[0] = u8::from(*self);
+    }
+}
+
+macro_rules! impl_fixed_bytes {
+    ($($t:ty),*) => {
+        $(
+            impl FixedBytes for $t {
+                const SIZE: usize = core::mem::size_of::<$t>();
+
+                fn read(bytes: &[u8]) -> $t {
+                    <$t>::from_le_bytes(bytes.try_into().unwrap())
+                }
+
+                fn write(&self, bytes: &mut [u8]) {
+                    bytes.copy_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+
+impl<const N: usize> FixedBytes for [u8; N] {
+    const SIZE: usize = N;
+
+    fn read(bytes: &[u8]) -> [u8; N] {
+        bytes.try_into().unwrap()
+    }
+
+    fn write(&self, bytes: &mut [u8]) {
+        bytes.copy_from_slice(self);
+    }
+}
+
+/// An immutable hash table constructed at compile time with perfect hashing,
+/// whose tables are stored in a binary blob rather than in Rust literals.
+///
+/// `quickphf_codegen` writes the pilots, the free slots and the entries of the
+/// table to a file, which the generated code embeds with `include_bytes!`.
+/// This spares the compiler from parsing and evaluating one literal per entry,
+/// which dominates the compile time of tables with millions of entries.
+///
+/// Keys and values are decoded from the blob on every access, so they are
+/// returned by value rather than by reference.
+#[derive(Debug)]
+pub struct BlobPhfMap<K, V> {
+    codomain_len: DivisorU64,
+    buckets: DivisorU64,
+    seed: u64,
+    len: usize,
+
+    blob: &'static [u8],
+    free_offset: usize,
+    entries_offset: usize,
+
+    marker: PhantomData<(K, V)>,
+}
+
+impl<K, V> BlobPhfMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        len: usize,
+        buckets: usize,
+        free_len: usize,
+        blob: &'static [u8],
+    ) -> BlobPhfMap<K, V> {
+        let free_offset = 2 * buckets;
+
+        BlobPhfMap {
+            codomain_len: DivisorU64::new((len + free_len) as u64),
+            buckets: DivisorU64::new(buckets as u64),
+            seed,
+            len,
+
+            blob,
+            free_offset,
+            entries_offset: free_offset + 4 * free_len,
+
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(SQUARE_ROOTS.len(), 1000);
+    /// assert_eq!(EMPTY_BLOB_MAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map does not contain any entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!SQUARE_ROOTS.is_empty());
+    /// assert!(EMPTY_BLOB_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+impl<K: FixedBytes, V: FixedBytes> BlobPhfMap<K, V> {
+    /// Returns the key stored in the map and its value, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(SQUARE_ROOTS.get_key_value(&144), Some((144, 12)));
+    /// assert_eq!(SQUARE_ROOTS.get_key_value(&145), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(K, V)>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let (stored, value) = self.entry(self.slot(key));
+        if key.equivalent(&stored) {
+            Some((stored, value))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(SQUARE_ROOTS.get(&998_001), Some(999));
+    /// assert_eq!(SQUARE_ROOTS.get(&2), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(SQUARE_ROOTS.contains_key(&0));
+    /// assert!(!SQUARE_ROOTS.contains_key(&1_000_000));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).is_some()
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let sum = SQUARE_ROOTS.iter().map(|(_, root)| u64::from(root)).sum::<u64>();
+    ///
+    /// assert_eq!(sum, 499_500);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            map: self,
+            slots: 0..self.len,
+        }
+    }
+
+    /// Returns the position in the table of entries to which the given key
+    /// is mapped.
+    fn slot<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        let key_hash = hash_key(key, self.seed);
+
+        let bucket = get_bucket(key_hash, self.buckets);
+        let pilot = u16::read(&self.blob[2 * bucket..2 * bucket + 2]);
+        let idx = get_index(key_hash, hash_pilot_value(pilot), self.codomain_len);
+
+        if idx < self.len {
+            idx
+        } else {
+            let start = self.free_offset + 4 * (idx - self.len);
+            u32::read(&self.blob[start..start + 4]) as usize
+        }
+    }
+
+    /// Decodes the entry at the given position of the table.
+    fn entry(&self, slot: usize) -> (K, V) {
+        let start = self.entries_offset + slot * (K::SIZE + V::SIZE);
+        let middle = start + K::SIZE;
+        let end = middle + V::SIZE;
+
+        (
+            K::read(&self.blob[start..middle]),
+            V::read(&self.blob[middle..end]),
+        )
+    }
+}
+
+impl<'a, K: FixedBytes, V: FixedBytes> IntoIterator for &'a BlobPhfMap<K, V> {
+    type Item = (K, V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of a `BlobPhfMap`.
+pub struct Iter<'a, K, V> {
+    map: &'a BlobPhfMap<K, V>,
+    slots: core::ops::Range<usize>,
+}
+
+impl<'a, K, V> Clone for Iter<'a, K, V> {
+    fn clone(&self) -> Self {
+        Iter {
+            map: self.map,
+            slots: self.slots.clone(),
+        }
+    }
+}
+
+impl<'a, K: FixedBytes, V: FixedBytes> Iterator for Iter<'a, K, V> {
+    type Item = (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next().map(|slot| self.map.entry(slot))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slots.size_hint()
+    }
+}
+
+impl<'a, K: FixedBytes, V: FixedBytes> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K: FixedBytes, V: FixedBytes> core::iter::FusedIterator for Iter<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_BLOB_MAP, SQUARE_ROOTS};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_BLOB_MAP.get(&0), None);
+        assert!(EMPTY_BLOB_MAP.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_every_entry() {
+        for root in 0..1000u16 {
+            let square = u32::from(root) * u32::from(root);
+            assert_eq!(SQUARE_ROOTS.get(&square), Some(root));
+        }
+        assert_eq!(SQUARE_ROOTS.iter().count(), 1000);
+    }
+}
@@ -0,0 +1,309 @@
+//! An immutable hash table with perfect hashing which is constructed at
+//! runtime in buffers borrowed from the caller.
+
+use core::hash::Hash;
+
+use quickdiv::DivisorU64;
+
+use crate::build::{build_in_place, BuildError};
+use crate::equivalent::Equivalent;
+use crate::map::{Iter, Keys, Values};
+use crate::shared::{get_slot, hash_key};
+
+/// An immutable hash table with perfect hashing which is constructed at
+/// runtime in buffers borrowed from the caller.
+///
+/// Unlike a [`PhfMap`](crate::PhfMap), which refers to `&'static` tables
+/// generated at compile time, a `BorrowedPhfMap` is built by
+/// [`build_in`](BorrowedPhfMap::build_in) without allocating, and refers to
+/// its tables for as long as their buffers are borrowed.
+#[derive(Debug)]
+pub struct BorrowedPhfMap<'a, K, V> {
+    codomain_len: DivisorU64,
+    buckets: DivisorU64,
+    seed: u64,
+
+    pilots_table: &'a [u16],
+    entries: &'a [(K, V)],
+    free: &'a [u32],
+}
+
+impl<'a, K, V> BorrowedPhfMap<'a, K, V> {
+    fn new(
+        seed: u64,
+        pilots_table: &'a [u16],
+        entries: &'a [(K, V)],
+        free: &'a [u32],
+    ) -> BorrowedPhfMap<'a, K, V> {
+        BorrowedPhfMap {
+            codomain_len: DivisorU64::new((entries.len() + free.len()) as u64),
+            buckets: DivisorU64::new(pilots_table.len() as u64),
+            seed,
+
+            pilots_table,
+            entries,
+            free,
+        }
+    }
+
+    /// Constructs a map from the given entries, without allocating.
+    ///
+    /// The table is stored in the supplied `pilots`, `entries` and `free`
+    /// buffers, which the map borrows, while `hashes` and `slots` are only
+    /// used as scratch space during construction. The required buffer sizes
+    /// for a given number of entries can be computed in a `const` context
+    /// using [`buffer_sizes`](crate::build::buffer_sizes).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`BuildError::BufferTooSmall`] if any of the buffers is too
+    /// short, and [`BuildError::DuplicateKey`] if `items` contains the same
+    /// key more than once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::build::{buffer_sizes, BufferSizes};
+    /// use quickphf::BorrowedPhfMap;
+    ///
+    /// const SIZES: BufferSizes = buffer_sizes(8);
+    ///
+    /// let mut pilots = [0; SIZES.pilots];
+    /// let mut entries = [("", 0); SIZES.entries];
+    /// let mut free = [0; SIZES.free];
+    /// let mut hashes = [0; SIZES.hashes];
+    /// let mut slots = [0; SIZES.slots];
+    ///
+    /// let items = [("red", 0xff0000), ("green", 0x00ff00), ("blue", 0x0000ff)];
+    /// let colors = BorrowedPhfMap::build_in(
+    ///     &mut pilots,
+    ///     &mut entries,
+    ///     &mut free,
+    ///     &mut hashes,
+    ///     &mut slots,
+    ///     items,
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(colors.len(), 3);
+    /// assert_eq!(colors.get("green"), Some(&0x00ff00));
+    /// assert_eq!(colors.get("yellow"), None);
+    /// ```
+    pub fn build_in<I>(
+        pilots: &'a mut [u16],
+        entries: &'a mut [(K, V)],
+        free: &'a mut [u32],
+        hashes: &mut [u64],
+        slots: &mut [u32],
+        items: I,
+    ) -> Result<BorrowedPhfMap<'a, K, V>, BuildError>
+    where
+        K: Eq + Hash,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let mut len = 0;
+        for item in items {
+            if len == entries.len() {
+                return Err(BuildError::BufferTooSmall);
+            }
+
+            entries[len] = item;
+            len += 1;
+        }
+
+        let entries = &mut entries[..len];
+        let placement = build_in_place(entries, |e| &e.0, pilots, free, hashes, slots)?;
+
+        let pilots: &'a [u16] = pilots;
+        let free: &'a [u32] = free;
+        Ok(BorrowedPhfMap::new(
+            placement.seed,
+            &pilots[..placement.buckets],
+            entries,
+            &free[..placement.codomain_len - len],
+        ))
+    }
+
+    /// Returns references to the key and value corresponding to the supplied
+    /// key, if present.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let key_hash = hash_key(key, self.seed);
+        let idx = get_slot(
+            key_hash,
+            self.pilots_table,
+            self.free,
+            self.buckets,
+            self.codomain_len,
+            self.len(),
+        );
+
+        let item = &self.entries[idx];
+        if key.equivalent(&item.0) {
+            Some((&item.0, &item.1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key, if present.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns a reference to the copy of the key stored in the map, if
+    /// present.
+    pub fn get_key<Q>(&self, key: &Q) -> Option<&K>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.0)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Returns the number of elements in the map.
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map does not contain any elements.
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.entries)
+    }
+
+    /// An iterator visiting all stored keys in arbitrary order.
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// An iterator visiting all stored values in arbitrary order.
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+}
+
+impl<'b, K, V> IntoIterator for &'b BorrowedPhfMap<'_, K, V> {
+    type Item = &'b (K, V);
+    type IntoIter = Iter<'b, K, V>;
+
+    fn into_iter(self) -> Iter<'b, K, V> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::vec;
+
+    use crate::build::buffer_sizes;
+
+    use super::*;
+
+    #[test]
+    fn test_build_in() {
+        let n = 2000;
+        let sizes = buffer_sizes(n);
+
+        let mut pilots = vec![0; sizes.pilots];
+        let mut entries = vec![(0, 0); sizes.entries];
+        let mut free = vec![0; sizes.free];
+        let mut hashes = vec![0; sizes.hashes];
+        let mut slots = vec![0; sizes.slots];
+
+        let items = (0..n as u64 - 10).map(|k| (k * 7919, k));
+        let map = BorrowedPhfMap::build_in(
+            &mut pilots,
+            &mut entries,
+            &mut free,
+            &mut hashes,
+            &mut slots,
+            items,
+        )
+        .unwrap();
+
+        assert_eq!(map.len(), n - 10);
+        for k in 0..n as u64 - 10 {
+            assert_eq!(map.get(&(k * 7919)), Some(&k));
+        }
+        assert_eq!(map.get(&1), None);
+        assert_eq!(map.iter().count(), n - 10);
+    }
+
+    #[test]
+    fn test_build_in_empty() {
+        let sizes = buffer_sizes(0);
+
+        let mut pilots = vec![0; sizes.pilots];
+        let mut entries: vec::Vec<(u32, u32)> = vec![];
+        let mut free = vec![0; sizes.free];
+        let mut slots = vec![0; sizes.slots];
+
+        let map = BorrowedPhfMap::build_in(
+            &mut pilots,
+            &mut entries,
+            &mut free,
+            &mut [],
+            &mut slots,
+            [],
+        )
+        .unwrap();
+
+        assert!(map.is_empty());
+        assert_eq!(map.get(&1), None);
+    }
+
+    #[test]
+    fn test_build_in_errors() {
+        let sizes = buffer_sizes(4);
+        let mut pilots = vec![0; sizes.pilots];
+        let mut entries = vec![(0, 0); sizes.entries];
+        let mut free = vec![0; sizes.free];
+        let mut hashes = vec![0; sizes.hashes];
+        let mut slots = vec![0; sizes.slots];
+
+        let items = (0..5).map(|k| (k, k));
+        let result = BorrowedPhfMap::build_in(
+            &mut pilots,
+            &mut entries,
+            &mut free,
+            &mut hashes,
+            &mut slots,
+            items,
+        );
+        assert_eq!(result.unwrap_err(), BuildError::BufferTooSmall);
+
+        let items = [(1, 1), (2, 2), (1, 3)];
+        let result = BorrowedPhfMap::build_in(
+            &mut pilots,
+            &mut entries,
+            &mut free,
+            &mut hashes,
+            &mut slots,
+            items,
+        );
+        assert_eq!(result.unwrap_err(), BuildError::DuplicateKey);
+    }
+}
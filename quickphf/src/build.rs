@@ -0,0 +1,394 @@
+//! Construction of perfect hash tables at runtime, without allocating.
+//!
+//! A [`BorrowedPhfMap`](crate::BorrowedPhfMap) is constructed by
+//! [`BorrowedPhfMap::build_in`](crate::BorrowedPhfMap::build_in), and all
+//! memory used by the construction, including scratch space, is supplied by
+//! the caller. The required buffer sizes for a given number of entries can be
+//! computed in a `const` context using [`buffer_sizes`], for example to
+//! declare fixed-capacity buffers.
+
+use core::fmt;
+use core::hash::Hash;
+
+use quickdiv::DivisorU64;
+
+use crate::shared::{get_bucket, get_index, hash_key, hash_pilot_value, table_sizes};
+
+/// Sentinel for an unoccupied slot of the codomain.
+const EMPTY: u32 = u32::MAX;
+
+/// Sizes of the buffers required to construct a table with up to a given
+/// number of entries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BufferSizes {
+    /// Length of the table of pilot values.
+    pub pilots: usize,
+    /// Length of the table of entries.
+    pub entries: usize,
+    /// Length of the table of free slots.
+    pub free: usize,
+    /// Length of the scratch space for key hashes.
+    pub hashes: usize,
+    /// Length of the scratch space for codomain slots.
+    pub slots: usize,
+}
+
+/// Returns the sizes of the buffers required to construct a table with at
+/// most `capacity` entries.
+///
+/// If no table with `capacity` entries can be constructed on this target,
+/// because the number of entries is not below `u32::MAX` or its codomain does
+/// not fit in a `usize`, every size is `usize::MAX`.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::build::{buffer_sizes, BufferSizes};
+///
+/// const SIZES: BufferSizes = buffer_sizes(100);
+///
+/// let pilots = [0u16; SIZES.pilots];
+/// let free = [0u32; SIZES.free];
+/// ```
+pub const fn buffer_sizes(capacity: usize) -> BufferSizes {
+    let (mut pilots, slots) = match table_sizes(capacity) {
+        Some(sizes) => sizes,
+        None => {
+            return BufferSizes {
+                pilots: usize::MAX,
+                entries: usize::MAX,
+                free: usize::MAX,
+                hashes: usize::MAX,
+                slots: usize::MAX,
+            }
+        }
+    };
+
+    // The number of buckets is not monotonic in the number of entries, since
+    // it drops whenever the number of entries reaches a power of two. Since
+    // `capacity` is below `u32::MAX`, `power` cannot overflow.
+    let mut power: usize = 2;
+    while power <= capacity {
+        if let Some((buckets, _)) = table_sizes(power - 1) {
+            if buckets > pilots {
+                pilots = buckets;
+            }
+        }
+        power *= 2;
+    }
+
+    // A table with `n` entries has `ceil(1000 * n / (990 - lg n))` slots,
+    // rounded up to an odd number, and thus fewer than
+    // `n * (10 + lg n) / (990 - lg n) + 2` free slots. Since this bound grows
+    // with `n`, and the full table has at least `capacity * (10 + lg capacity)
+    // / (990 - lg capacity)` free slots, a smaller table has at most two more
+    // free slots than the full one.
+    let free = if capacity == 0 {
+        1
+    } else {
+        slots - capacity + 2
+    };
+
+    BufferSizes {
+        pilots,
+        entries: capacity,
+        free,
+        hashes: capacity,
+        slots,
+    }
+}
+
+/// An error that occurred while constructing a table at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// One of the supplied buffers is too short to hold the table.
+    BufferTooSmall,
+    /// The supplied entries contain the same key more than once.
+    DuplicateKey,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BuildError::BufferTooSmall => write!(f, "buffer too small to hold table"),
+            BuildError::DuplicateKey => write!(f, "duplicate key"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+/// Parameters of a perfect hash function constructed by [`build_in_place`].
+#[derive(Debug)]
+pub(crate) struct Placement {
+    pub seed: u64,
+    pub buckets: usize,
+    pub codomain_len: usize,
+}
+
+/// Constructs a PTHash perfect hash function for the keys of `entries`, and
+/// reorders `entries` into codomain order.
+///
+/// On success, the first `buckets` elements of `pilots` and the first
+/// `codomain_len - entries.len()` elements of `free` contain the pilot table
+/// and the free slot table.
+pub(crate) fn build_in_place<T, H, F>(
+    entries: &mut [T],
+    key: F,
+    pilots: &mut [u16],
+    free: &mut [u32],
+    hashes: &mut [u64],
+    slots: &mut [u32],
+) -> Result<Placement, BuildError>
+where
+    H: Eq + Hash + ?Sized,
+    F: Fn(&T) -> &H,
+{
+    let n = entries.len();
+    let (buckets, codomain_len) = match table_sizes(n) {
+        Some(sizes) => sizes,
+        None => return Err(BuildError::BufferTooSmall),
+    };
+    let extra_slots = codomain_len - n;
+
+    if pilots.len() < buckets
+        || free.len() < extra_slots
+        || hashes.len() < n
+        || slots.len() < codomain_len
+    {
+        return Err(BuildError::BufferTooSmall);
+    }
+
+    let pilots = &mut pilots[..buckets];
+    let free = &mut free[..extra_slots];
+    let hashes = &mut hashes[..n];
+    let slots = &mut slots[..codomain_len];
+
+    if n == 0 {
+        pilots.fill(0);
+        free.fill(0);
+        return Ok(Placement {
+            seed: 0,
+            buckets,
+            codomain_len,
+        });
+    }
+
+    let buckets_len = DivisorU64::new(buckets as u64);
+    let codomain = DivisorU64::new(codomain_len as u64);
+
+    let mut attempt = 1u64;
+    let seed = loop {
+        let seed = attempt << 32;
+        attempt += 1;
+
+        if try_place(
+            entries,
+            &key,
+            seed,
+            buckets_len,
+            codomain,
+            pilots,
+            hashes,
+            slots,
+        )? {
+            break seed;
+        }
+    };
+
+    // At this point `slots` is a table of size `codomain_len`, but with `n`
+    // values. We need to move the items from the back into the empty slots at
+    // the front, and compute the table `free` that will point to their new
+    // locations.
+    free.fill(0);
+
+    let mut back_idx = n;
+    for front_idx in 0..n {
+        if slots[front_idx] != EMPTY {
+            continue;
+        }
+
+        while slots[back_idx] == EMPTY {
+            back_idx += 1;
+        }
+
+        slots[front_idx] = slots[back_idx];
+        free[back_idx - n] = front_idx as u32;
+        back_idx += 1;
+    }
+
+    // Now the entry at position `slots[i]` belongs at position `i`.
+    permute(&mut slots[..n], |a, b| entries.swap(a, b));
+
+    Ok(Placement {
+        seed,
+        buckets,
+        codomain_len,
+    })
+}
+
+/// Rearranges a sequence in place so that the element at position
+/// `permutation[i]` moves to position `i`, by following the cycles of the
+/// permutation and calling `swap` on pairs of positions.
+///
+/// Leaves `permutation` as the identity.
+fn permute<F: FnMut(usize, usize)>(permutation: &mut [u32], mut swap: F) {
+    for start in 0..permutation.len() {
+        let mut current = start;
+        loop {
+            let source = permutation[current] as usize;
+            permutation[current] = current as u32;
+
+            if source == start {
+                break;
+            }
+
+            swap(current, source);
+            current = source;
+        }
+    }
+}
+
+/// Attempts to find pilot values for all buckets with the given seed.
+///
+/// On success, returns `true` and `slots` maps every occupied position of the
+/// codomain to the position of its entry in `entries`.
+#[allow(clippy::too_many_arguments)]
+fn try_place<T, H, F>(
+    entries: &mut [T],
+    key: &F,
+    seed: u64,
+    buckets_len: DivisorU64,
+    codomain_len: DivisorU64,
+    pilots: &mut [u16],
+    hashes: &mut [u64],
+    slots: &mut [u32],
+) -> Result<bool, BuildError>
+where
+    H: Eq + Hash + ?Sized,
+    F: Fn(&T) -> &H,
+{
+    // We begin by hashing the entries, grouping them by buckets, and checking
+    // for collisions. Every key is hashed only once, and the entries are
+    // sorted through a permutation stored in `slots`, which is not needed
+    // until the pilot search.
+    for (hash, entry) in hashes.iter_mut().zip(entries.iter()) {
+        *hash = hash_key(key(entry), seed);
+    }
+
+    let order = &mut slots[..hashes.len()];
+    for (idx, slot) in order.iter_mut().enumerate() {
+        *slot = idx as u32;
+    }
+    order.sort_unstable_by_key(|&idx| {
+        let hash = hashes[idx as usize];
+        (get_bucket(hash, buckets_len), hash)
+    });
+    permute(order, |a, b| {
+        entries.swap(a, b);
+        hashes.swap(a, b);
+    });
+
+    for idx in 1..hashes.len() {
+        if hashes[idx - 1] == hashes[idx] {
+            if key(&entries[idx - 1]) == key(&entries[idx]) {
+                return Err(BuildError::DuplicateKey);
+            }
+            return Ok(false);
+        }
+    }
+
+    let bucket_end = |start: usize| {
+        let bucket = get_bucket(hashes[start], buckets_len);
+        hashes[start..]
+            .iter()
+            .position(|&hash| get_bucket(hash, buckets_len) != bucket)
+            .map_or(hashes.len(), |len| start + len)
+    };
+
+    let mut max_bucket_size = 0;
+    let mut start = 0;
+    while start < hashes.len() {
+        let end = bucket_end(start);
+        max_bucket_size = usize::max(max_bucket_size, end - start);
+        start = end;
+    }
+
+    pilots.fill(0);
+    slots.fill(EMPTY);
+
+    // Buckets are processed in order of decreasing size.
+    for size in (1..=max_bucket_size).rev() {
+        let mut start = 0;
+        while start < hashes.len() {
+            let end = bucket_end(start);
+            if end - start == size {
+                match find_pilot(&hashes[start..end], start, codomain_len, slots) {
+                    Some(pilot) => pilots[get_bucket(hashes[start], buckets_len)] = pilot,
+                    None => return Ok(false),
+                }
+            }
+            start = end;
+        }
+    }
+
+    Ok(true)
+}
+
+/// Searches for a pilot value which maps the given bucket to unoccupied
+/// slots, and marks those slots as occupied.
+fn find_pilot(
+    bucket: &[u64],
+    offset: usize,
+    codomain_len: DivisorU64,
+    slots: &mut [u32],
+) -> Option<u16> {
+    'pilots: for pilot in 0u16..=u16::MAX {
+        let pilot_hash = hash_pilot_value(pilot);
+
+        for (i, &hash) in bucket.iter().enumerate() {
+            let destination = get_index(hash, pilot_hash, codomain_len);
+
+            // This also catches collisions within the bucket, since its
+            // earlier entries have already been placed.
+            if slots[destination] != EMPTY {
+                for &placed in &bucket[..i] {
+                    slots[get_index(placed, pilot_hash, codomain_len)] = EMPTY;
+                }
+                continue 'pilots;
+            }
+
+            slots[destination] = (offset + i) as u32;
+        }
+
+        return Some(pilot);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffer_sizes_cover_smaller_tables() {
+        let capacity = 5000;
+        let sizes = buffer_sizes(capacity);
+
+        for n in 0..=capacity {
+            let (buckets, codomain_len) = table_sizes(n).unwrap();
+            assert!(buckets <= sizes.pilots);
+            assert!(codomain_len <= sizes.slots);
+            assert!(codomain_len - n <= sizes.free);
+        }
+    }
+
+    #[test]
+    fn test_table_sizes_limit() {
+        let limit = u32::MAX as usize;
+        assert_eq!(table_sizes(limit), None);
+        assert_eq!(buffer_sizes(limit).pilots, usize::MAX);
+    }
+}
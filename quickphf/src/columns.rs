@@ -0,0 +1,173 @@
+//! An immutable set of keys constructed at compile time with perfect hashing,
+//! which shares its slots with any number of columns of values.
+
+use core::hash::Hash;
+
+use crate::equivalent::Equivalent;
+use crate::RawPhfMap;
+
+/// An immutable set of keys constructed at compile time with perfect hashing,
+/// which shares its slots with any number of columns of values.
+///
+/// A `PhfColumns` plays the role of several [`PhfMap`](crate::PhfMap)s over
+/// the same keys, but the perfect hash function is only solved and stored
+/// once, and so are the keys. Each column is a plain slice of values generated
+/// by `quickphf_codegen`, in which the value of every key is at the slot
+/// returned by [`slot`](PhfColumns::slot).
+#[derive(Debug)]
+pub struct PhfColumns<K: 'static> {
+    raw_map: RawPhfMap<K, K>,
+}
+
+impl<K> PhfColumns<K> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the table—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        keys: &'static [K],
+        free: &'static [u32],
+    ) -> PhfColumns<K> {
+        PhfColumns {
+            raw_map: RawPhfMap::new(seed, pilots_table, keys, free),
+        }
+    }
+
+    /// Returns the slot of the given key in every column, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let slot = UNITS.slot("km").unwrap();
+    ///
+    /// assert_eq!(UNIT_FACTORS[slot], 1_000_000);
+    /// assert_eq!(UNIT_NAMES[slot], "kilometre");
+    /// assert_eq!(UNITS.slot("ft"), None);
+    /// ```
+    pub fn slot<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let slot = self.raw_map.slot_of(key);
+        if key.equivalent(&self.raw_map.as_slice()[slot]) {
+            Some(slot)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value of the given key in `column`, if the
+    /// key is present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `column` is shorter than the table, which can only happen if
+    /// it was not generated together with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(UNITS.get("g", UNIT_DIMENSIONS), Some(&"mass"));
+    /// assert_eq!(UNITS.get("lb", UNIT_DIMENSIONS), None);
+    /// ```
+    pub fn get<'c, Q, V>(&self, key: &Q, column: &'c [V]) -> Option<&'c V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.slot(key).map(|slot| &column[slot])
+    }
+
+    /// Returns `true` if the table contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(UNITS.contains_key("mg"));
+    /// assert!(!UNITS.contains_key("oz"));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.slot(key).is_some()
+    }
+
+    /// Returns the number of keys in the table, which is also the length of
+    /// every column.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(UNITS.len(), 8);
+    /// assert_eq!(UNIT_NAMES.len(), 8);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.raw_map.len()
+    }
+
+    /// Returns `true` if the table does not contain any keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!UNITS.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.raw_map.is_empty()
+    }
+
+    /// Returns the keys of the table as a slice, in the order of the slots of
+    /// the columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let masses = UNITS
+    ///     .keys()
+    ///     .iter()
+    ///     .zip(UNIT_DIMENSIONS)
+    ///     .filter(|(_, &dimension)| dimension == "mass")
+    ///     .count();
+    ///
+    /// assert_eq!(masses, 4);
+    /// ```
+    pub const fn keys(&self) -> &'static [K] {
+        self.raw_map.as_slice()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{UNITS, UNIT_DIMENSIONS, UNIT_FACTORS, UNIT_NAMES};
+
+    #[test]
+    fn test_columns_line_up() {
+        assert_eq!(UNIT_FACTORS.len(), UNITS.len());
+        assert_eq!(UNIT_DIMENSIONS.len(), UNITS.len());
+        assert_eq!(UNIT_NAMES.len(), UNITS.len());
+
+        for (slot, key) in UNITS.keys().iter().enumerate() {
+            assert_eq!(UNITS.slot(key), Some(slot));
+        }
+
+        assert_eq!(UNITS.get("t", UNIT_NAMES), Some(&"tonne"));
+        assert_eq!(UNITS.get("t", UNIT_FACTORS), Some(&1_000_000_000));
+    }
+}
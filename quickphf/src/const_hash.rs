@@ -0,0 +1,209 @@
+//! Hashing and comparison of keys in `const` contexts.
+//!
+//! Trait methods cannot be called from a `const fn`, so the hashes of the
+//! supported key types are computed by a port of `wyhash` which produces the
+//! same values as [`hash_key`](crate::shared::hash_key).
+
+pub(crate) const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+const P0: u64 = 0xa076_1d64_78bd_642f;
+const P1: u64 = 0xe703_7ed1_a0b4_28db;
+const P2: u64 = 0x8ebc_6af0_9c88_c6e3;
+const P3: u64 = 0x5899_65cc_7537_4cc3;
+const P4: u64 = 0x1d8e_4e27_c47d_124f;
+const P5: u64 = 0xeb44_acca_b455_d165;
+
+/// Port of `wyhash::WyHash` that can be used in a `const` context, and
+/// produces the same hashes.
+#[derive(Clone, Copy)]
+pub(crate) struct ConstHasher {
+    h: u64,
+    size: u64,
+}
+
+impl ConstHasher {
+    pub(crate) const fn with_seed(seed: u64) -> ConstHasher {
+        ConstHasher { h: seed, size: 0 }
+    }
+
+    pub(crate) const fn write(self, bytes: &[u8]) -> ConstHasher {
+        if bytes.is_empty() {
+            ConstHasher {
+                h: self.h ^ P0,
+                size: self.size,
+            }
+        } else {
+            ConstHasher {
+                h: wyhash_core(bytes, self.h),
+                size: self.size + bytes.len() as u64,
+            }
+        }
+    }
+
+    pub(crate) const fn finish(self) -> u64 {
+        wymum(self.h, self.size ^ P5)
+    }
+}
+
+const fn wymum(a: u64, b: u64) -> u64 {
+    let r = (a as u128) * (b as u128);
+    ((r >> 64) ^ r) as u64
+}
+
+const fn read32(data: &[u8], at: usize) -> u64 {
+    (data[at + 3] as u64) << 24
+        | (data[at + 2] as u64) << 16
+        | (data[at + 1] as u64) << 8
+        | (data[at] as u64)
+}
+
+const fn read64(data: &[u8], at: usize) -> u64 {
+    read32(data, at + 4) << 32 | read32(data, at)
+}
+
+const fn read64_swapped(data: &[u8], at: usize) -> u64 {
+    read32(data, at) << 32 | read32(data, at + 4)
+}
+
+const fn read_byte(data: &[u8], at: usize) -> u64 {
+    data[at] as u64
+}
+
+const fn read_rest(data: &[u8], at: usize) -> u64 {
+    let b = at;
+    match data.len() - at {
+        1 => read_byte(data, b),
+        2 => read_byte(data, b + 1) << 8 | read_byte(data, b),
+        3 => read_byte(data, b + 1) << 16 | read_byte(data, b) << 8 | read_byte(data, b + 2),
+        4 => read32(data, at),
+        5 => read32(data, at) << 8 | read_byte(data, b + 4),
+        6 => read32(data, at) << 16 | read_byte(data, b + 5) << 8 | read_byte(data, b + 4),
+        7 => {
+            read32(data, at) << 24
+                | read_byte(data, b + 5) << 16
+                | read_byte(data, b + 4) << 8
+                | read_byte(data, b + 6)
+        }
+        _ => read64_swapped(data, at),
+    }
+}
+
+const fn wyhash_core(bytes: &[u8], mut seed: u64) -> u64 {
+    let len = bytes.len();
+
+    let mut at = 0;
+    while at + 32 <= len {
+        seed = wymum(
+            seed ^ P0,
+            wymum(read64(bytes, at) ^ P1, read64(bytes, at + 8) ^ P2)
+                ^ wymum(read64(bytes, at + 16) ^ P3, read64(bytes, at + 24) ^ P4),
+        );
+        at += 32;
+    }
+    seed ^= P0;
+
+    if len & 31 != 0 {
+        let start = len & !31;
+        seed = match ((len - 1) & 31) / 8 {
+            0 => wymum(seed, read_rest(bytes, start) ^ P1),
+            1 => wymum(
+                read64_swapped(bytes, start) ^ seed,
+                read_rest(bytes, start + 8) ^ P2,
+            ),
+            2 => {
+                wymum(
+                    read64_swapped(bytes, start) ^ seed,
+                    read64_swapped(bytes, start + 8) ^ P2,
+                ) ^ wymum(seed, read_rest(bytes, start + 16) ^ P3)
+            }
+            _ => {
+                wymum(
+                    read64_swapped(bytes, start) ^ seed,
+                    read64_swapped(bytes, start + 8) ^ P2,
+                ) ^ wymum(
+                    read64_swapped(bytes, start + 16) ^ seed,
+                    read_rest(bytes, start + 24) ^ P4,
+                )
+            }
+        };
+    }
+
+    seed
+}
+
+/// Hashes a string the same way as its [`Hash`](core::hash::Hash) impl.
+pub(crate) const fn hash_str(key: &str, seed: u64) -> u64 {
+    hash_str_bytes(key.as_bytes(), seed)
+}
+
+/// Hashes the bytes of a string the same way as the [`Hash`](core::hash::Hash)
+/// impl of the string, whether or not they are valid UTF-8.
+pub(crate) const fn hash_str_bytes(key: &[u8], seed: u64) -> u64 {
+    ConstHasher::with_seed(seed)
+        .write(key)
+        .write(&[0xff])
+        .finish()
+}
+
+/// Hashes a byte slice the same way as its [`Hash`](core::hash::Hash) impl.
+pub(crate) const fn hash_bytes(key: &[u8], seed: u64) -> u64 {
+    ConstHasher::with_seed(seed)
+        .write(&key.len().to_ne_bytes())
+        .write(key)
+        .finish()
+}
+
+macro_rules! int_hash_fns {
+    ($($name:ident => $int:ty),*) => {
+        $(
+            /// Hashes an integer the same way as its [`Hash`](core::hash::Hash) impl.
+            pub(crate) const fn $name(key: $int, seed: u64) -> u64 {
+                ConstHasher::with_seed(seed).write(&key.to_ne_bytes()).finish()
+            }
+        )*
+    };
+}
+
+int_hash_fns!(
+    hash_u8 => u8, hash_u16 => u16, hash_u32 => u32, hash_u64 => u64, hash_u128 => u128,
+    hash_usize => usize, hash_i8 => i8, hash_i16 => i16, hash_i32 => i32, hash_i64 => i64,
+    hash_i128 => i128, hash_isize => isize
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shared::hash_key;
+
+    #[test]
+    fn test_const_hasher_matches_wyhash() {
+        let mut bytes = [0u8; 100];
+        for (i, byte) in bytes.iter_mut().enumerate() {
+            *byte = (i * 37 + 11) as u8;
+        }
+        for len in 0..bytes.len() {
+            let key = &bytes[..len];
+            let seed = (len as u64) << 32;
+            assert_eq!(hash_bytes(key, seed), hash_key(key, seed));
+        }
+
+        assert_eq!(hash_str("Chade", 7), hash_key("Chade", 7));
+        assert_eq!(hash_str_bytes(b"Chade", 7), hash_key("Chade", 7));
+        assert_eq!(hash_i16(-5, 7), hash_key(&-5i16, 7));
+        assert_eq!(hash_u128(u128::MAX, 7), hash_key(&u128::MAX, 7));
+    }
+}
@@ -0,0 +1,563 @@
+//! Construction of perfect hash maps in `const` contexts.
+//!
+//! For small tables, such as a few dozen keywords, the functions in this
+//! module can be used to construct a [`PhfMapArray`] directly in Rust source,
+//! without generating code in a build script. The pilot search runs during
+//! constant evaluation, so it is only suitable for tables of up to a few
+//! hundred entries, and duplicate keys are reported as compile-time errors.
+//!
+//! There is one function per supported key type, since the key hashes have
+//! to be computed in a `const fn`. The [`const_map!`](crate::const_map!)
+//! macro declares a constant holding such a table, and fills in the number
+//! of entries, buckets and free slots of its type, which are otherwise given
+//! by [`buckets`] and [`free_slots`].
+//!
+//! This module requires the `const-map` feature and Rust 1.61 or later.
+//!
+//! # Examples
+//!
+//! ```
+//! use quickphf::const_map;
+//!
+//! const_map! {
+//!     const KEYWORDS: PhfMapArray<&str, u32> =
+//!         str_map(&[("fn", 0), ("let", 1), ("mut", 2), ("impl", 3)]);
+//! }
+//!
+//! assert_eq!(KEYWORDS.get("mut"), Some(&2));
+//! assert_eq!(KEYWORDS.get("struct"), None);
+//! ```
+//!
+//! The functions can also be called directly, with the lengths written out:
+//!
+//! ```
+//! use quickphf::const_map::{buckets, free_slots, str_map};
+//! use quickphf::PhfMapArray;
+//!
+//! const KEYWORDS: PhfMapArray<&str, u32, 4, { buckets(4) }, { free_slots(4) }> =
+//!     str_map(&[("fn", 0), ("let", 1), ("mut", 2), ("impl", 3)]);
+//!
+//! assert_eq!(KEYWORDS.get("impl"), Some(&3));
+//! ```
+//!
+//! Duplicate keys are rejected at compile time:
+//!
+//! ```compile_fail
+//! use quickphf::const_map;
+//!
+//! const_map! {
+//!     const KEYWORDS: PhfMapArray<&str, u32> = str_map(&[("fn", 0), ("let", 1), ("fn", 2)]);
+//! }
+//! ```
+
+use crate::const_hash::{
+    bytes_eq, hash_bytes, hash_i128, hash_i16, hash_i32, hash_i64, hash_i8, hash_isize, hash_str,
+    hash_u128, hash_u16, hash_u32, hash_u64, hash_u8, hash_usize,
+};
+use crate::map_array::PhfMapArray;
+use crate::shared::{hash_pilot_value, table_sizes};
+
+/// Returns the number of buckets of a table with `len` entries, i.e., the
+/// `B` parameter of the corresponding [`PhfMapArray`].
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::const_map::buckets;
+///
+/// assert_eq!(buckets(4), 4);
+/// ```
+pub const fn buckets(len: usize) -> usize {
+    sizes(len).0
+}
+
+/// Returns the number of free slots of a table with `len` entries, i.e., the
+/// `F` parameter of the corresponding [`PhfMapArray`].
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::const_map::free_slots;
+///
+/// assert_eq!(free_slots(4), 1);
+/// ```
+pub const fn free_slots(len: usize) -> usize {
+    sizes(len).1 - len
+}
+
+/// Returns the number of buckets and the size of the codomain of a table with
+/// `len` entries.
+const fn sizes(len: usize) -> (usize, usize) {
+    match table_sizes(len) {
+        Some(sizes) => sizes,
+        None => panic!("const map has too many entries"),
+    }
+}
+
+/// Declares constants holding [`PhfMapArray`]s constructed by the functions
+/// of the [`const_map`](mod@crate::const_map) module, computing the number of
+/// entries, buckets and free slots of their types.
+///
+/// Each constant is declared as `const NAME: PhfMapArray<K, V> = f(&[...]);`,
+/// where `f` is the function of the `const_map` module for keys of type `K`,
+/// such as `str_map` or `u32_map`, and the entries are written out as a
+/// literal array.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::const_map;
+///
+/// const_map! {
+///     /// HTTP methods.
+///     pub const METHODS: PhfMapArray<&str, u8> =
+///         str_map(&[("GET", 0), ("HEAD", 1), ("POST", 2), ("PUT", 3)]);
+///
+///     const NO_PORTS: PhfMapArray<u16, &str> = u16_map(&[]);
+/// }
+///
+/// assert_eq!(METHODS.get("POST"), Some(&2));
+/// assert!(NO_PORTS.is_empty());
+/// ```
+#[macro_export]
+macro_rules! const_map {
+    ($(
+        $(#[$attr:meta])*
+        $vis:vis const $name:ident: PhfMapArray<$k:ty, $v:ty> =
+            $f:ident(&[$(($key:expr, $value:expr)),* $(,)?]);
+    )*) => {
+        $(
+            $(#[$attr])*
+            $vis const $name: $crate::PhfMapArray<
+                $k,
+                $v,
+                { <[&str]>::len(&[$(stringify!($key)),*]) },
+                { $crate::const_map::buckets(<[&str]>::len(&[$(stringify!($key)),*])) },
+                { $crate::const_map::free_slots(<[&str]>::len(&[$(stringify!($key)),*])) },
+            > = $crate::const_map::$f(&[$(($key, $value)),*]);
+        )*
+    };
+}
+
+macro_rules! const_map_fn {
+    (
+        $name:ident, $key:ty, $example:literal, $lookup:literal,
+        hash: $hash:ident,
+        eq: |$a:ident, $b:ident| $eq:expr
+    ) => {
+        #[doc = concat!(
+            "Constructs a [`PhfMapArray`] with `", stringify!($key), "` keys in a `const` context.\n",
+            "\n",
+            "# Panics\n",
+            "\n",
+            "Panics, which in a `const` context is a compile-time error, if two\n",
+            "entries have the same key, or if `B` and `F` do not equal\n",
+            "[`buckets(N)`](buckets) and [`free_slots(N)`](free_slots).\n",
+            "\n",
+            "# Examples\n",
+            "\n",
+            "```\n",
+            "use quickphf::const_map::{buckets, free_slots, ", stringify!($name), "};\n",
+            "use quickphf::PhfMapArray;\n",
+            "\n",
+            "const MAP: PhfMapArray<", stringify!($key), ", u32, 3, { buckets(3) }, { free_slots(3) }> =\n",
+            "    ", stringify!($name), "(&[", $example, "]);\n",
+            "\n",
+            "assert_eq!(MAP.get(", $lookup, "), Some(&20));\n",
+            "```"
+        )]
+        pub const fn $name<V: Copy, const N: usize, const B: usize, const F: usize>(
+            entries: &[($key, V); N],
+        ) -> PhfMapArray<$key, V, N, B, F> {
+            check_lengths(N, B, F);
+
+            if N == 0 {
+                return PhfMapArray::new(0, [0; B], *entries, [0; F]);
+            }
+
+            let mut i = 0;
+            while i < N {
+                let mut j = i + 1;
+                while j < N {
+                    let $a = entries[i].0;
+                    let $b = entries[j].0;
+                    if $eq {
+                        panic!("duplicate key in const map");
+                    }
+                    j += 1;
+                }
+                i += 1;
+            }
+
+            let mut attempt = 1u64;
+            loop {
+                let seed = attempt << 32;
+                attempt += 1;
+
+                let mut hashes = [0; N];
+                let mut i = 0;
+                while i < N {
+                    hashes[i] = $hash(entries[i].0, seed);
+                    i += 1;
+                }
+
+                if let Some(layout) = place::<N, B, F>(&hashes) {
+                    let mut ordered = *entries;
+                    let mut slot = 0;
+                    while slot < N {
+                        ordered[slot] = entries[layout.order[slot]];
+                        slot += 1;
+                    }
+
+                    return PhfMapArray::new(seed, layout.pilots, ordered, layout.free);
+                }
+            }
+        }
+    };
+}
+
+const_map_fn!(
+    str_map, &'static str, r#"("a", 10), ("b", 20), ("c", 30)"#, r#""b""#,
+    hash: hash_str,
+    eq: |a, b| bytes_eq(a.as_bytes(), b.as_bytes())
+);
+
+const_map_fn!(
+    bytes_map, &'static [u8], r#"(b"a", 10), (b"b", 20), (b"c", 30)"#, r#"&b"b"[..]"#,
+    hash: hash_bytes,
+    eq: |a, b| bytes_eq(a, b)
+);
+
+macro_rules! const_map_fn_for_int {
+    ($($name:ident => $int:ty => $hash:ident),*) => {
+        $(
+            const_map_fn!(
+                $name, $int, "(1, 10), (2, 20), (3, 30)", "&2",
+                hash: $hash,
+                eq: |a, b| a == b
+            );
+        )*
+    };
+}
+
+const_map_fn_for_int!(
+    u8_map => u8 => hash_u8,
+    u16_map => u16 => hash_u16,
+    u32_map => u32 => hash_u32,
+    u64_map => u64 => hash_u64,
+    u128_map => u128 => hash_u128,
+    usize_map => usize => hash_usize,
+    i8_map => i8 => hash_i8,
+    i16_map => i16 => hash_i16,
+    i32_map => i32 => hash_i32,
+    i64_map => i64 => hash_i64,
+    i128_map => i128 => hash_i128,
+    isize_map => isize => hash_isize
+);
+
+const fn check_lengths(n: usize, b: usize, f: usize) {
+    let (buckets, codomain_len) = sizes(n);
+    assert!(
+        b == buckets && n + f == codomain_len,
+        "const map lengths must equal `N`, `buckets(N)` and `free_slots(N)`"
+    );
+}
+
+/// Marks an unoccupied slot of the codomain.
+const EMPTY: usize = usize::MAX;
+
+/// Perfect hash function found by [`place`], with `order[i]` the index of the
+/// entry which belongs at position `i` of the table.
+struct Layout<const N: usize, const B: usize, const F: usize> {
+    pilots: [u16; B],
+    order: [usize; N],
+    free: [u32; F],
+}
+
+/// Attempts to find pilot values for the given key hashes, following the
+/// same steps as [`build_in_place`](crate::build::build_in_place).
+const fn place<const N: usize, const B: usize, const F: usize>(
+    hashes: &[u64; N],
+) -> Option<Layout<N, B, F>> {
+    let codomain_len = (N + F) as u64;
+
+    // Entries with equal hashes can never be separated, so a new seed is
+    // needed.
+    if has_equal_hashes(hashes) {
+        return None;
+    }
+
+    let buckets: Buckets<N, B> = group_by_bucket(hashes);
+
+    // The codomain is split into the positions of the table itself and the
+    // positions past its end, which are later remapped through `free`.
+    let mut front = [EMPTY; N];
+    let mut back = [EMPTY; F];
+    let mut pilots = [0; B];
+
+    // Buckets are processed in order of decreasing size.
+    let mut size = buckets.max_size;
+    while size > 0 {
+        let mut bucket = 0;
+        while bucket < B {
+            if buckets.sizes[bucket] == size {
+                let start = buckets.starts[bucket];
+                let pilot = match find_pilot(
+                    hashes,
+                    &buckets.members,
+                    start,
+                    size,
+                    codomain_len,
+                    &front,
+                    &back,
+                ) {
+                    Some(pilot) => pilot,
+                    None => return None,
+                };
+
+                pilots[bucket] = pilot;
+                let pilot_hash = hash_pilot_value(pilot);
+                let mut i = start;
+                while i < start + size {
+                    let entry = buckets.members[i];
+                    let destination = ((hashes[entry] ^ pilot_hash) % codomain_len) as usize;
+                    if destination < N {
+                        front[destination] = entry;
+                    } else {
+                        back[destination - N] = entry;
+                    }
+                    i += 1;
+                }
+            }
+            bucket += 1;
+        }
+        size -= 1;
+    }
+
+    let (order, free) = compact(&front, &back);
+    Some(Layout {
+        pilots,
+        order,
+        free,
+    })
+}
+
+/// Returns `true` if two of the given hashes are equal.
+const fn has_equal_hashes<const N: usize>(hashes: &[u64; N]) -> bool {
+    let mut i = 0;
+    while i < N {
+        let mut j = i + 1;
+        while j < N {
+            if hashes[i] == hashes[j] {
+                return true;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+/// Entries grouped by bucket, with the indices of the entries of bucket `b`
+/// at `members[starts[b]..starts[b] + sizes[b]]`.
+struct Buckets<const N: usize, const B: usize> {
+    sizes: [usize; B],
+    starts: [usize; B],
+    members: [usize; N],
+    max_size: usize,
+}
+
+/// Groups the entries by bucket with a counting sort.
+const fn group_by_bucket<const N: usize, const B: usize>(hashes: &[u64; N]) -> Buckets<N, B> {
+    let buckets = B as u64;
+
+    let mut sizes = [0; B];
+    let mut i = 0;
+    while i < N {
+        sizes[(hashes[i] % buckets) as usize] += 1;
+        i += 1;
+    }
+
+    let mut starts = [0; B];
+    let mut max_size = 0;
+    let mut bucket = 0;
+    while bucket < B {
+        if bucket > 0 {
+            starts[bucket] = starts[bucket - 1] + sizes[bucket - 1];
+        }
+        if sizes[bucket] > max_size {
+            max_size = sizes[bucket];
+        }
+        bucket += 1;
+    }
+
+    let mut members = [0; N];
+    let mut filled = [0; B];
+    let mut i = 0;
+    while i < N {
+        let bucket = (hashes[i] % buckets) as usize;
+        members[starts[bucket] + filled[bucket]] = i;
+        filled[bucket] += 1;
+        i += 1;
+    }
+
+    Buckets {
+        sizes,
+        starts,
+        members,
+        max_size,
+    }
+}
+
+/// Searches for a pilot value which maps the bucket made up of
+/// `members[start..start + size]` to unoccupied slots.
+const fn find_pilot<const N: usize, const F: usize>(
+    hashes: &[u64; N],
+    members: &[usize; N],
+    start: usize,
+    size: usize,
+    codomain_len: u64,
+    front: &[usize; N],
+    back: &[usize; F],
+) -> Option<u16> {
+    let mut pilot = 0u32;
+    while pilot <= u16::MAX as u32 {
+        let pilot_hash = hash_pilot_value(pilot as u16);
+        if fits(
+            hashes,
+            members,
+            start,
+            size,
+            pilot_hash,
+            codomain_len,
+            front,
+            back,
+        ) {
+            return Some(pilot as u16);
+        }
+        pilot += 1;
+    }
+
+    None
+}
+
+/// Moves the entries placed past the end of the table into the holes at its
+/// front, returning the final order of the entries and the table of free
+/// slots.
+const fn compact<const N: usize, const F: usize>(
+    front: &[usize; N],
+    back: &[usize; F],
+) -> ([usize; N], [u32; F]) {
+    let mut order = [0; N];
+    let mut free = [0; F];
+    let mut back_idx = 0;
+    let mut slot = 0;
+    while slot < N {
+        if front[slot] == EMPTY {
+            while back[back_idx] == EMPTY {
+                back_idx += 1;
+            }
+            order[slot] = back[back_idx];
+            free[back_idx] = slot as u32;
+            back_idx += 1;
+        } else {
+            order[slot] = front[slot];
+        }
+        slot += 1;
+    }
+
+    (order, free)
+}
+
+/// Returns `true` if the given pilot maps all entries of a bucket to distinct
+/// unoccupied slots.
+#[allow(clippy::too_many_arguments)]
+const fn fits<const N: usize, const F: usize>(
+    hashes: &[u64; N],
+    members: &[usize; N],
+    start: usize,
+    size: usize,
+    pilot_hash: u64,
+    codomain_len: u64,
+    front: &[usize; N],
+    back: &[usize; F],
+) -> bool {
+    let mut i = start;
+    while i < start + size {
+        let destination = ((hashes[members[i]] ^ pilot_hash) % codomain_len) as usize;
+        let occupied = if destination < N {
+            front[destination] != EMPTY
+        } else {
+            back[destination - N] != EMPTY
+        };
+        if occupied {
+            return false;
+        }
+
+        let mut j = start;
+        while j < i {
+            if ((hashes[members[j]] ^ pilot_hash) % codomain_len) as usize == destination {
+                return false;
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        const EMPTY: PhfMapArray<&str, u32, 0, { buckets(0) }, { free_slots(0) }> = str_map(&[]);
+
+        const_map! {
+            const EMPTY_BYTES: PhfMapArray<&[u8], u32> = bytes_map(&[]);
+        }
+
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.get("Chade"), None);
+        assert_eq!(EMPTY_BYTES.get(&b"Chade"[..]), None);
+    }
+
+    #[test]
+    fn test_macro_lengths() {
+        const_map! {
+            const PRIMES: PhfMapArray<u8, bool> = u8_map(&[
+                (2, true), (3, true), (5, true), (7, true), (11, true), (13, true),
+            ]);
+        }
+
+        assert_eq!(PRIMES.len(), 6);
+        for key in [2, 3, 5, 7, 11, 13] {
+            assert_eq!(PRIMES.get(&key), Some(&true));
+        }
+        assert_eq!(PRIMES.get(&4), None);
+    }
+
+    #[test]
+    fn test_large_table() {
+        const fn squares() -> [(u32, u32); 200] {
+            let mut entries = [(0, 0); 200];
+            let mut i = 0;
+            while i < 200 {
+                entries[i] = (i as u32, (i * i) as u32);
+                i += 1;
+            }
+            entries
+        }
+
+        const MAP: PhfMapArray<u32, u32, 200, { buckets(200) }, { free_slots(200) }> =
+            u32_map(&squares());
+
+        for i in 0..200 {
+            assert_eq!(MAP.get(&i), Some(&(i * i)));
+        }
+        assert_eq!(MAP.get(&200), None);
+    }
+}
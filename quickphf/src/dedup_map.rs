@@ -0,0 +1,267 @@
+//! An immutable hash table constructed at compile time with perfect hashing,
+//! which stores each distinct value only once.
+
+use core::hash::Hash;
+
+use crate::equivalent::Equivalent;
+use crate::RawPhfMap;
+
+/// An immutable hash table constructed at compile time with perfect hashing,
+/// which stores each distinct value only once.
+///
+/// Instead of holding `(K, V)` entries like a [`PhfMap`](crate::PhfMap), each
+/// entry of a `DedupPhfMap` holds its key together with the position of its
+/// value in a shared table of distinct values, which is smaller when many keys
+/// share a few large values.
+#[derive(Debug)]
+pub struct DedupPhfMap<K: 'static, V: 'static> {
+    raw_map: RawPhfMap<K, (K, u32)>,
+    values: &'static [V],
+}
+
+impl<K, V> DedupPhfMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        entries: &'static [(K, u32)],
+        free: &'static [u32],
+        values: &'static [V],
+    ) -> DedupPhfMap<K, V> {
+        DedupPhfMap {
+            raw_map: RawPhfMap::new(seed, pilots_table, entries, free),
+            values,
+        }
+    }
+
+    /// Returns the key stored in the map and a reference to its value, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(CURRENCIES.get_key_value("EC"), Some((&"EC", &"USD")));
+    /// assert_eq!(CURRENCIES.get_key_value("GB"), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let entry = self.raw_map.value_of(key);
+        if key.equivalent(&entry.0) {
+            Some((&entry.0, &self.values[entry.1 as usize]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(CURRENCIES.get("FR"), Some(&"EUR"));
+    /// assert_eq!(CURRENCIES.get("CH"), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(CURRENCIES.contains_key("JP"));
+    /// assert!(!CURRENCIES.contains_key("CN"));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(CURRENCIES.len(), 9);
+    /// assert_eq!(EMPTY_DEDUP_MAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.raw_map.len()
+    }
+
+    /// Returns the number of distinct values in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(CURRENCIES.values_len(), 3);
+    /// ```
+    pub const fn values_len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the map does not contain any entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!CURRENCIES.is_empty());
+    /// assert!(EMPTY_DEDUP_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.raw_map.is_empty()
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut euro_area = CURRENCIES
+    ///     .iter()
+    ///     .filter(|&(_, &currency)| currency == "EUR")
+    ///     .map(|(&country, _)| country)
+    ///     .collect::<Vec<_>>();
+    /// euro_area.sort();
+    ///
+    /// assert_eq!(&euro_area, &["DE", "ES", "FR", "IT", "NL"]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            entries: self.raw_map.as_slice().iter(),
+            values: self.values,
+        }
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(CURRENCIES.keys().count(), 9);
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// An iterator visiting each distinct value once, in the order in which
+    /// they first appeared in the input of `quickphf_codegen`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let currencies = CURRENCIES.distinct_values().copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&currencies, &["EUR", "USD", "JPY"]);
+    /// ```
+    pub fn distinct_values(&self) -> core::slice::Iter<'_, V> {
+        self.values.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a DedupPhfMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+#[derive(Clone)]
+/// An iterator over the entries of a `DedupPhfMap`.
+pub struct Iter<'a, K: 'static, V: 'static> {
+    entries: core::slice::Iter<'a, (K, u32)>,
+    values: &'a [V],
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let values = self.values;
+        self.entries
+            .next()
+            .map(|entry| (&entry.0, &values[entry.1 as usize]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.entries.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for Iter<'a, K, V> {}
+
+#[derive(Clone)]
+/// An iterator over the keys of a `DedupPhfMap`.
+pub struct Keys<'a, K: 'static, V: 'static> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|e| e.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for Keys<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{CURRENCIES, EMPTY_DEDUP_MAP};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_DEDUP_MAP.get("Nighteyes"), None);
+        assert!(EMPTY_DEDUP_MAP.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_values_are_shared() {
+        let us = CURRENCIES.get("US").unwrap();
+        let sv = CURRENCIES.get("SV").unwrap();
+        assert!(core::ptr::eq(us, sv));
+    }
+}
@@ -0,0 +1,162 @@
+//! Lookups of keys by values of a different type.
+
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+
+/// A type whose values can be used to look up keys of type `K` in the tables
+/// of this crate, such as a [`PhfMap`](crate::PhfMap) or a
+/// [`PhfSet`](crate::PhfSet).
+///
+/// Like [`Borrow`], this allows querying a table with a type other than its
+/// key type, but it does not require `K` to be borrowable as `Self`. This is
+/// needed for composite keys, such as tuples or structs containing
+/// `&'static str`s, which cannot be borrowed as their counterparts containing
+/// shorter-lived references or owned strings.
+///
+/// It is implemented for every `Q` such that `K: Borrow<Q>`, so every query
+/// which worked with `Borrow` works with `Equivalent`. Tuples of other types
+/// can be compared component by component by wrapping them in a [`Tuple`].
+///
+/// The only lookups which still require `K: Borrow<Q>` are those which order
+/// or index the queried key, namely [`SortedPhfMap::range`],
+/// [`SortedPhfSet::range`] and the lookups of a [`StaticMap`], those of a
+/// [`RawPhfMap`] and a [`PackedRawPhfMap`], which never compare keys, and the
+/// lookups of an `OverlayPhfMap`, whose runtime changes are kept in a
+/// [`std::collections::HashMap`].
+///
+/// [`SortedPhfMap::range`]: crate::SortedPhfMap::range
+/// [`SortedPhfSet::range`]: crate::SortedPhfSet::range
+/// [`StaticMap`]: crate::StaticMap
+/// [`RawPhfMap`]: crate::RawPhfMap
+/// [`PackedRawPhfMap`]: crate::PackedRawPhfMap
+/// [`std::collections::HashMap`]: https://doc.rust-lang.org/std/collections/struct.HashMap.html
+///
+/// An implementation must be consistent with hashing: if `q.equivalent(&k)`,
+/// then `q` and `k` must have the same [`Hash`], that is, they must write the
+/// same sequence of values to the hasher. Otherwise, lookups of `q` may fail.
+///
+/// # Examples
+///
+/// ```
+/// use core::hash::{Hash, Hasher};
+/// use quickphf::examples::*;
+/// use quickphf::Equivalent;
+///
+/// struct Route {
+///     method: String,
+///     path: String,
+/// }
+///
+/// /// Hashes like the tuple `(&str, &str)`, by hashing each component.
+/// impl Hash for Route {
+///     fn hash<H: Hasher>(&self, state: &mut H) {
+///         self.method.hash(state);
+///         self.path.hash(state);
+///     }
+/// }
+///
+/// impl Equivalent<(&'static str, &'static str)> for Route {
+///     fn equivalent(&self, key: &(&'static str, &'static str)) -> bool {
+///         self.method == key.0 && self.path == key.1
+///     }
+/// }
+///
+/// let route = Route {
+///     method: "POST".to_string(),
+///     path: "/login".to_string(),
+/// };
+///
+/// assert_eq!(ROUTES.get(&route), Some(&3));
+/// ```
+pub trait Equivalent<K: ?Sized> {
+    /// Returns `true` if `self` is equal to `key`.
+    fn equivalent(&self, key: &K) -> bool;
+}
+
+impl<Q, K> Equivalent<K> for Q
+where
+    Q: Eq + ?Sized,
+    K: Borrow<Q> + ?Sized,
+{
+    fn equivalent(&self, key: &K) -> bool {
+        self == key.borrow()
+    }
+}
+
+/// A tuple which looks up tuple keys by comparing their components with
+/// [`PartialEq`].
+///
+/// For example, a `Tuple((String, &str))` is equivalent to a key of type
+/// `(&'static str, &'static str)`. It is hashed exactly like the tuple it
+/// wraps, so each of its components must be hashed like the corresponding
+/// component of the key, as `String` and `&str` are.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::equivalent::Tuple;
+/// use quickphf::examples::*;
+///
+/// let request = String::from("GET /about HTTP/1.1");
+/// let mut parts = request.split(' ');
+/// let method = parts.next().unwrap();
+/// let path = parts.next().unwrap();
+///
+/// assert_eq!(ROUTES.get(&Tuple((method, path))), Some(&1));
+/// assert_eq!(
+///     ROUTES.get(&Tuple((String::from("GET"), String::from("/")))),
+///     Some(&0)
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Tuple<T>(pub T);
+
+impl<T: Hash> Hash for Tuple<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
+macro_rules! impl_tuple_equivalent {
+    ($(($($q:ident $k:ident $idx:tt),+)),*) => {
+        $(
+            impl<$($q: PartialEq<$k>, $k),+> Equivalent<($($k,)+)> for Tuple<($($q,)+)> {
+                fn equivalent(&self, key: &($($k,)+)) -> bool {
+                    $(self.0.$idx == key.$idx)&&+
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple_equivalent!(
+    (A KA 0),
+    (A KA 0, B KB 1),
+    (A KA 0, B KB 1, C KC 2),
+    (A KA 0, B KB 1, C KC 2, D KD 3),
+    (A KA 0, B KB 1, C KC 2, D KD 3, E KE 4),
+    (A KA 0, B KB 1, C KC 2, D KD 3, E KE 4, F KF 5)
+);
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use std::string::String;
+
+    use super::Tuple;
+    use crate::examples::ROUTES;
+
+    #[test]
+    fn test_tuple_lookups() {
+        for (key, value) in ROUTES.iter() {
+            let owned = (String::from(key.0), String::from(key.1));
+            assert_eq!(
+                ROUTES.get(&Tuple((owned.0.as_str(), owned.1.as_str()))),
+                Some(value)
+            );
+            assert_eq!(ROUTES.get(&Tuple(owned.clone())), Some(value));
+            assert_eq!(ROUTES.get(&Tuple((owned.1, owned.0))), None);
+        }
+    }
+}
@@ -8,6 +8,90 @@ pub static HOLIDAYS_PER_MONTH: crate::RawPhfMap<&'static str, i32> = crate::RawP
 pub static EMPTY_RAW_MAP: crate::RawPhfMap<&'static str, i32> =
     crate::RawPhfMap::new(0, &[0], &[], &[0]);
 
+pub fn month_number<Q>(key: &Q) -> Option<&'static u8>
+where
+    Q: ::core::hash::Hash + crate::Equivalent<&'static str> + ?Sized,
+{
+    static PILOTS: [u16; 9] = [0, 0, 1, 0, 4, 0, 1, 4, 11];
+    static FREE: [u32; 1] = [0];
+    static ENTRIES: [(&str, u8); 12] = [
+        ("may", 5),
+        ("jun", 6),
+        ("jul", 7),
+        ("aug", 8),
+        ("feb", 2),
+        ("apr", 4),
+        ("dec", 12),
+        ("mar", 3),
+        ("sep", 9),
+        ("jan", 1),
+        ("oct", 10),
+        ("nov", 11),
+    ];
+
+    let hash = crate::shared::hash_key(key, 4294967296);
+    let bucket =
+        crate::shared::rem_with_magic(hash, 37809151880104273718152734159085356829, 9) as usize;
+    let pilot_hash = crate::shared::hash_pilot_value(PILOTS[bucket]);
+    let idx = crate::shared::rem_with_magic(
+        hash ^ pilot_hash,
+        26175566686226035651028815956289862420,
+        13,
+    ) as usize;
+    let slot = if idx < 12 {
+        idx
+    } else {
+        FREE[idx - 12] as usize
+    };
+
+    let entry = &ENTRIES[slot];
+    if crate::Equivalent::equivalent(key, &entry.0) {
+        Some(&entry.1)
+    } else {
+        None
+    }
+}
+
+pub static LETTER_POSITIONS: crate::PackedRawPhfMap<&'static str, u8> = crate::PackedRawPhfMap::new(
+    4294967296,
+    &[0, 0, 0, 2, 5, 0, 5, 0, 1, 7, 5, 10, 0, 2, 0],
+    &[(); 26],
+    &[5],
+    5,
+    &[14673624275037242042, 9699306874419349996, 2],
+);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Solid,
+    Liquid,
+    Gas,
+}
+
+impl crate::packed::PackedValue for State {
+    fn to_bits(self) -> u32 {
+        self as u32
+    }
+
+    fn from_bits(bits: u32) -> State {
+        match bits {
+            0 => State::Solid,
+            1 => State::Liquid,
+            _ => State::Gas,
+        }
+    }
+}
+
+pub static ELEMENT_STATES: crate::PackedRawPhfMap<&'static str, State> =
+    crate::PackedRawPhfMap::new(
+        4294967296,
+        &[4, 1, 7, 9, 11, 4, 0, 0, 1, 1, 0, 0, 4],
+        &[(); 22],
+        &[10],
+        2,
+        &[4441174974720],
+    );
+
 pub static FOURTH_POWERS_TO_ROOTS: crate::PhfMap<i32, i32> = crate::PhfMap::new(
     4294967296,
     &[0, 0, 0, 1, 1, 1, 2],
@@ -26,8 +110,531 @@ pub static FOURTH_POWERS_TO_ROOTS: crate::PhfMap<i32, i32> = crate::PhfMap::new(
     &[3],
 );
 
+pub static CONTENT_TYPES: crate::PhfMap<&'static str, &'static str> = crate::PhfMap::new(
+    4294967296,
+    &[0, 0, 5, 0, 0, 1, 10],
+    &[
+        ("png", "image/png"),
+        ("gif", "image/gif"),
+        ("json", "application/json"),
+        ("css", "text/css"),
+        ("svg", "image/svg+xml"),
+        ("html", "text/html"),
+        ("jpg", "image/jpeg"),
+        ("txt", "text/plain"),
+        ("js", "text/javascript"),
+    ],
+    &[1, 7],
+);
+
+pub static ROUTES: crate::PhfMap<(&'static str, &'static str), u16> = crate::PhfMap::new(
+    4294967296,
+    &[0, 1, 0, 0, 0],
+    &[
+        (("POST", "/login"), 3),
+        (("POST", "/logout"), 4),
+        (("GET", "/"), 0),
+        (("GET", "/login"), 2),
+        (("GET", "/about"), 1),
+    ],
+    &[0, 1],
+);
+
 pub static EMPTY_MAP: crate::PhfMap<&'static str, i32> = crate::PhfMap::new(0, &[0], &[], &[0]);
 
+pub const SQUARES_ARRAY: crate::PhfMapArray<u32, u32, 5, 5, 2> = crate::PhfMapArray::new(
+    4294967296,
+    [0, 0, 0, 0, 1],
+    [(4, 16), (5, 25), (2, 4), (1, 1), (3, 9)],
+    [2, 4],
+);
+
+pub const EMPTY_MAP_ARRAY: crate::PhfMapArray<&'static str, i32, 0, 1, 1> =
+    crate::PhfMapArray::new(0, [0], [], [0]);
+
+pub static HTTP_STATUSES: crate::PhfBiMap<u16, &'static str> = crate::PhfBiMap::new(
+    4294967296,
+    &[0, 0, 0, 1, 3],
+    &[
+        (200, "OK"),
+        (201, "Created"),
+        (404, "Not Found"),
+        (301, "Moved Permanently"),
+        (500, "Internal Server Error"),
+    ],
+    &[0, 0],
+    4294967296,
+    &[0, 0, 1, 0, 0],
+    &[3, 0, 1, 2, 4],
+    &[0, 1],
+);
+
+pub static EMPTY_BIMAP: crate::PhfBiMap<&'static str, i32> =
+    crate::PhfBiMap::new(0, &[0], &[], &[0], 0, &[0], &[], &[0]);
+
+pub static MIME_TYPES: crate::PhfMultiMap<&'static str, &'static str> = crate::PhfMultiMap::new(
+    4294967296,
+    &[0, 0, 0, 0],
+    &[("ico", 0), ("js", 2), ("png", 4), ("xml", 5)],
+    &[1],
+    &[
+        "image/x-icon",
+        "image/vnd.microsoft.icon",
+        "application/javascript",
+        "text/javascript",
+        "image/png",
+        "application/xml",
+        "text/xml",
+    ],
+);
+
+pub static EMPTY_MULTIMAP: crate::PhfMultiMap<&'static str, &'static str> =
+    crate::PhfMultiMap::new(0, &[0], &[], &[0], &[]);
+
+pub static CURRENCIES: crate::DedupPhfMap<&'static str, &'static str> = crate::DedupPhfMap::new(
+    4294967296,
+    &[0, 0, 1, 1, 7, 0, 0],
+    &[
+        ("ES", 0),
+        ("US", 1),
+        ("FR", 0),
+        ("DE", 0),
+        ("EC", 1),
+        ("JP", 2),
+        ("NL", 0),
+        ("IT", 0),
+        ("SV", 1),
+    ],
+    &[0, 4],
+    &["EUR", "USD", "JPY"],
+);
+
+pub static EMPTY_DEDUP_MAP: crate::DedupPhfMap<&'static str, &'static str> =
+    crate::DedupPhfMap::new(0, &[0], &[], &[0], &[]);
+
+pub static TIMEZONES: crate::AliasPhfMap<&'static str, i32> = crate::AliasPhfMap::new(
+    4294967296,
+    &[0, 0, 0, 0, 3, 2, 5],
+    &[
+        ("Etc/Zulu", 3),
+        ("UTC", 3),
+        ("Asia/Calcutta", 1),
+        ("Asia/Kolkata", 1),
+        ("America/New_York", 2),
+        ("Europe/Kiev", 0),
+        ("Europe/Kyiv", 0),
+        ("Etc/UTC", 3),
+        ("US/Eastern", 2),
+    ],
+    &[2, 0],
+    &[
+        ("Europe/Kyiv", 120),
+        ("Asia/Kolkata", 330),
+        ("America/New_York", -300),
+        ("Etc/UTC", 0),
+    ],
+);
+
+pub static EMPTY_ALIAS_MAP: crate::AliasPhfMap<&'static str, i32> =
+    crate::AliasPhfMap::new(0, &[0], &[], &[0], &[]);
+
+pub static STATUS_REASONS: crate::SoaPhfMap<u16, &'static str> = crate::SoaPhfMap::new(
+    4294967296,
+    &[0, 6, 12, 0, 8, 0, 1],
+    &[503, 403, 201, 404, 400, 200, 301, 204, 500, 502],
+    &[8],
+    &[
+        "Service Unavailable",
+        "Forbidden",
+        "Created",
+        "Not Found",
+        "Bad Request",
+        "OK",
+        "Moved Permanently",
+        "No Content",
+        "Internal Server Error",
+        "Bad Gateway",
+    ],
+);
+
+pub static EMPTY_SOA_MAP: crate::SoaPhfMap<u16, &'static str> =
+    crate::SoaPhfMap::new(0, &[0], &[], &[0], &[]);
+
+pub static SQUARE_ROOTS: crate::BlobPhfMap<u32, u16> = crate::BlobPhfMap::new(
+    4294967296,
+    1000,
+    367,
+    21,
+    include_bytes!("square_roots.bin"),
+);
+
+pub static EMPTY_BLOB_MAP: crate::BlobPhfMap<u32, u16> =
+    crate::BlobPhfMap::new(0, 0, 1, 1, include_bytes!("empty_blob_map.bin"));
+
+pub static DIGIT_NAMES: crate::StaticMap<u8, &'static str> = crate::StaticMap::direct(
+    0,
+    &[
+        (0, "zero"),
+        (1, "one"),
+        (2, "two"),
+        (3, "three"),
+        (4, "four"),
+        (5, "five"),
+        (6, "six"),
+        (7, "seven"),
+        (8, "eight"),
+        (9, "nine"),
+    ],
+);
+
+pub static COMPASS_BEARINGS: crate::StaticMap<&'static str, u16> =
+    crate::StaticMap::linear(&[("N", 0), ("E", 90), ("S", 180), ("W", 270)]);
+
+pub static GREEK_LETTERS: crate::StaticMap<&'static str, u8> =
+    crate::StaticMap::phf(crate::PhfMap::new(
+        4294967296,
+        &[6, 1, 2, 13, 6, 2, 17, 20, 0, 0, 4, 4, 0, 0],
+        &[
+            ("pi", 16),
+            ("lambda", 11),
+            ("beta", 2),
+            ("delta", 4),
+            ("zeta", 6),
+            ("mu", 12),
+            ("upsilon", 20),
+            ("sigma", 18),
+            ("iota", 9),
+            ("xi", 14),
+            ("theta", 8),
+            ("kappa", 10),
+            ("tau", 19),
+            ("phi", 21),
+            ("rho", 17),
+            ("eta", 7),
+            ("omega", 24),
+            ("nu", 13),
+            ("alpha", 1),
+            ("epsilon", 5),
+            ("psi", 23),
+            ("omicron", 15),
+            ("gamma", 3),
+            ("chi", 22),
+        ],
+        &[9],
+    ));
+
+pub static SORTED_GREEK_LETTERS: crate::StaticMap<&'static str, u8> = crate::StaticMap::sorted(&[
+    ("alpha", 1),
+    ("beta", 2),
+    ("chi", 22),
+    ("delta", 4),
+    ("epsilon", 5),
+    ("eta", 7),
+    ("gamma", 3),
+    ("iota", 9),
+    ("kappa", 10),
+    ("lambda", 11),
+    ("mu", 12),
+    ("nu", 13),
+    ("omega", 24),
+    ("omicron", 15),
+    ("phi", 21),
+    ("pi", 16),
+    ("psi", 23),
+    ("rho", 17),
+    ("sigma", 18),
+    ("tau", 19),
+    ("theta", 8),
+    ("upsilon", 20),
+    ("xi", 14),
+    ("zeta", 6),
+]);
+
+pub static EMPTY_STATIC_MAP: crate::StaticMap<&'static str, u8> = crate::StaticMap::linear(&[]);
+
+pub static C_KEYWORDS: crate::KeywordPhfMap<str, u8> = crate::KeywordPhfMap::new(
+    4294967296,
+    &[2, 0, 4, 3, 0, 0, 6, 7, 3, 0, 1, 4, 0, 16, 3, 79],
+    &[
+        ("return", 20),
+        ("if", 16),
+        ("enum", 11),
+        ("switch", 26),
+        ("short", 21),
+        ("typedef", 27),
+        ("for", 14),
+        ("int", 17),
+        ("volatile", 31),
+        ("auto", 1),
+        ("union", 28),
+        ("unsigned", 29),
+        ("else", 10),
+        ("default", 7),
+        ("const", 5),
+        ("struct", 25),
+        ("goto", 15),
+        ("case", 3),
+        ("extern", 12),
+        ("char", 4),
+        ("continue", 6),
+        ("long", 18),
+        ("do", 8),
+        ("double", 9),
+        ("break", 2),
+        ("float", 13),
+        ("void", 30),
+        ("sizeof", 23),
+        ("signed", 22),
+        ("while", 32),
+        ("static", 24),
+        ("register", 19),
+    ],
+    &[19],
+    &[0, 2],
+);
+
+pub static HTTP_METHODS: crate::KeywordPhfMap<[u8], u8> = crate::KeywordPhfMap::new(
+    4294967296,
+    &[0, 0, 2, 0, 0, 9, 0],
+    &[
+        (b"PATCH", 9),
+        (b"OPTIONS", 7),
+        (b"CONNECT", 6),
+        (b"HEAD", 2),
+        (b"TRACE", 8),
+        (b"DELETE", 5),
+        (b"POST", 3),
+        (b"PUT", 4),
+        (b"GET", 1),
+    ],
+    &[0, 6],
+    &[0],
+);
+
+pub static EMPTY_KEYWORD_MAP: crate::KeywordPhfMap<str, u8> =
+    crate::KeywordPhfMap::new(0, &[0], &[], &[0], &[]);
+
+pub static HEADER_IDS: crate::NormalizedPhfMap<u8, crate::normalized_map::AsciiCaseInsensitive> =
+    crate::NormalizedPhfMap::new(
+        4294967296,
+        &[1, 2, 0, 0, 0, 18],
+        &[
+            ("referer", 4),
+            ("cookie", 6),
+            ("content-length", 1),
+            ("content-type", 2),
+            ("accept", 0),
+            (
+                "x-a-very-long-header-name-which-does-not-fit-in-sixty-four-bytes",
+                7,
+            ),
+            ("host", 3),
+            ("user-agent", 5),
+        ],
+        &[0],
+    );
+
+pub static SETTINGS: crate::NormalizedPhfMap<
+    bool,
+    crate::normalized_map::Trim<crate::normalized_map::UnicodeLowercase>,
+> = crate::NormalizedPhfMap::new(
+    4294967296,
+    &[0, 0, 4, 0],
+    &[
+        ("strasse", false),
+        ("ελληνικά", false),
+        ("größe", true),
+        ("verbose", true),
+    ],
+    &[2],
+);
+
+pub static EMPTY_NORMALIZED_MAP: crate::NormalizedPhfMap<
+    u8,
+    crate::normalized_map::AsciiCaseInsensitive,
+> = crate::NormalizedPhfMap::new(0, &[0], &[], &[0]);
+
+pub static CAPITALS: crate::ArenaPhfMap<str, crate::arena::Span> = crate::ArenaPhfMap::new(
+    4294967296,
+    &[0, 1, 0, 0, 1, 1],
+    &[
+        (
+            crate::arena::Span::new(16, 6),
+            crate::arena::Span::new(56, 9),
+        ),
+        (
+            crate::arena::Span::new(9, 7),
+            crate::arena::Span::new(51, 5),
+        ),
+        (
+            crate::arena::Span::new(0, 9),
+            crate::arena::Span::new(39, 12),
+        ),
+        (
+            crate::arena::Span::new(35, 4),
+            crate::arena::Span::new(80, 4),
+        ),
+        (
+            crate::arena::Span::new(22, 5),
+            crate::arena::Span::new(65, 8),
+        ),
+        (
+            crate::arena::Span::new(27, 8),
+            crate::arena::Span::new(73, 7),
+        ),
+    ],
+    &[2],
+    "ArgentinaBoliviaBrazilChileColombiaPeruBuenos AiresSucreBrasíliaSantiagoBogotáLima",
+);
+
+pub static FILE_SIGNATURES: crate::ArenaPhfMap<[u8], &'static str> = crate::ArenaPhfMap::new(
+    4294967296,
+    &[1, 0, 0, 0, 0],
+    &[
+        (crate::arena::Span::new(12, 4), "application/zip"),
+        (crate::arena::Span::new(16, 4), "application/x-elf"),
+        (crate::arena::Span::new(4, 4), "image/png"),
+        (crate::arena::Span::new(8, 4), "image/gif"),
+        (crate::arena::Span::new(0, 4), "application/pdf"),
+    ],
+    &[1, 3],
+    b"%PDF\x89PNGGIF8PK\x03\x04\x7fELF",
+);
+
+pub static EMPTY_ARENA_MAP: crate::ArenaPhfMap<str, u32> =
+    crate::ArenaPhfMap::new(0, &[0], &[], &[0], "");
+
+pub static PLANET_MOONS: crate::OrderedPhfMap<&'static str, u32> = crate::OrderedPhfMap::new(
+    4294967296,
+    &[0, 0, 0, 0, 0, 1],
+    &[0, 2, 5, 1, 4, 3, 6, 7],
+    &[
+        ("mercury", 0),
+        ("venus", 0),
+        ("earth", 1),
+        ("mars", 2),
+        ("jupiter", 95),
+        ("saturn", 146),
+        ("uranus", 28),
+        ("neptune", 16),
+    ],
+    &[2],
+);
+
+pub static EMPTY_ORDERED_MAP: crate::OrderedPhfMap<&'static str, u32> =
+    crate::OrderedPhfMap::new(0, &[0], &[], &[], &[0]);
+
+pub static KEYWORDS: crate::PhfInterner = crate::PhfInterner::new(
+    4294967296,
+    &[2, 0, 0, 3, 0, 0, 1],
+    &[0, 2, 5, 7, 9, 3, 8, 6, 1, 4],
+    &[
+        "as", "break", "const", "continue", "crate", "else", "enum", "fn", "for", "if",
+    ],
+    &[0],
+);
+
+pub static EMPTY_INTERNER: crate::PhfInterner = crate::PhfInterner::new(0, &[0], &[], &[], &[0]);
+
+#[derive(Debug)]
+pub struct Country {
+    pub iso2: &'static str,
+    pub iso3: &'static str,
+    pub name: &'static str,
+}
+
+pub struct Iso2;
+
+impl crate::records::RecordKey<Country> for Iso2 {
+    type Key = str;
+    const INDEX: usize = ISO2;
+
+    fn key(record: &Country) -> &str {
+        record.iso2
+    }
+}
+
+pub struct Iso3;
+
+impl crate::records::RecordKey<Country> for Iso3 {
+    type Key = str;
+    const INDEX: usize = ISO3;
+
+    fn key(record: &Country) -> &str {
+        record.iso3
+    }
+}
+
+pub const ISO2: usize = 0;
+pub const ISO3: usize = 1;
+
+pub static COUNTRIES: crate::PhfRecords<Country> = crate::PhfRecords::new(
+    &[
+        Country {
+            iso2: "DE",
+            iso3: "DEU",
+            name: "Germany",
+        },
+        Country {
+            iso2: "FR",
+            iso3: "FRA",
+            name: "France",
+        },
+        Country {
+            iso2: "JP",
+            iso3: "JPN",
+            name: "Japan",
+        },
+        Country {
+            iso2: "BR",
+            iso3: "BRA",
+            name: "Brazil",
+        },
+        Country {
+            iso2: "CA",
+            iso3: "CAN",
+            name: "Canada",
+        },
+    ],
+    &[
+        crate::RawPhfMap::new(4294967296, &[0, 5, 0, 2, 0], &[3, 4, 2, 0, 1], &[0, 0]),
+        crate::RawPhfMap::new(4294967296, &[0, 0, 0, 0, 0], &[3, 4, 2, 1, 0], &[0, 3]),
+    ],
+);
+
+pub static ELEMENTS: crate::SortedPhfMap<&'static str, u32> = crate::SortedPhfMap::new(
+    4294967296,
+    &[0, 0, 2, 0, 4, 0, 15],
+    &[
+        ("hydrogen", 1),
+        ("nitrogen", 7),
+        ("carbon", 6),
+        ("neon", 10),
+        ("fluorine", 9),
+        ("helium", 2),
+        ("boron", 5),
+        ("beryllium", 4),
+        ("lithium", 3),
+        ("oxygen", 8),
+    ],
+    &[5],
+    &[7, 6, 2, 4, 5, 0, 8, 3, 1, 9],
+);
+
+pub static EMPTY_SORTED_MAP: crate::SortedPhfMap<&'static str, u32> =
+    crate::SortedPhfMap::new(0, &[0], &[], &[0], &[]);
+
+pub static FRUIT_COUNTERS: crate::PhfMap<&'static str, ::core::sync::atomic::AtomicU64> = {
+    static ENTRIES: [(&str, ::core::sync::atomic::AtomicU64); 4] = [
+        ("banana", ::core::sync::atomic::AtomicU64::new(0)),
+        ("cherry", ::core::sync::atomic::AtomicU64::new(0)),
+        ("grape", ::core::sync::atomic::AtomicU64::new(0)),
+        ("apple", ::core::sync::atomic::AtomicU64::new(0)),
+    ];
+
+    crate::PhfMap::new(4294967296, &[1, 1, 0, 4], &ENTRIES, &[0])
+};
+
 pub static DIGITS: crate::PhfSet<i32> = crate::PhfSet::new(
     4294967296,
     &[8, 0, 0, 9, 0, 0, 6],
@@ -41,4 +648,54 @@ pub static EVEN_DIGITS: crate::PhfSet<i32> =
 pub static PRIME_DIGITS: crate::PhfSet<i32> =
     crate::PhfSet::new(4294967296, &[2, 0, 0, 0], &[5, 7, 2, 3], &[2]);
 
+pub static HOP_BY_HOP_HEADERS: crate::PhfSet<&'static str> = crate::PhfSet::new(
+    4294967296,
+    &[3, 0, 0, 2, 3, 1],
+    &[
+        "keep-alive",
+        "proxy-authenticate",
+        "transfer-encoding",
+        "connection",
+        "trailer",
+        "upgrade",
+        "proxy-authorization",
+        "te",
+    ],
+    &[5],
+);
+
 pub static EMPTY_SET: crate::PhfSet<u64> = crate::PhfSet::new(0, &[0], &[], &[0]);
+
+pub static RAINBOW: crate::SortedPhfSet<&'static str> = crate::SortedPhfSet::new(
+    4294967296,
+    &[1, 0, 0, 3, 1, 1, 0],
+    &[
+        "green", "blue", "yellow", "indigo", "violet", "red", "orange",
+    ],
+    &[0, 3],
+    &[1, 0, 3, 6, 5, 4, 2],
+);
+
+pub static UNITS: crate::PhfColumns<&'static str> = crate::PhfColumns::new(
+    4294967296,
+    &[0, 0, 10, 4, 4, 0],
+    &["g", "mg", "mm", "m", "km", "cm", "t", "kg"],
+    &[0],
+);
+
+pub static UNIT_FACTORS: &[u64] = &[1000, 1, 1, 1000, 1000000, 10, 1000000000, 1000000];
+
+pub static UNIT_DIMENSIONS: &[&str] = &[
+    "mass", "mass", "length", "length", "length", "length", "mass", "mass",
+];
+
+pub static UNIT_NAMES: &[&str] = &[
+    "gram",
+    "milligram",
+    "millimetre",
+    "metre",
+    "kilometre",
+    "centimetre",
+    "tonne",
+    "kilogram",
+];
@@ -0,0 +1,442 @@
+//! A hash map with a fixed set of keys known at compile time, whose values
+//! can be modified at runtime.
+
+use core::fmt;
+use core::hash::Hash;
+use core::marker::PhantomData;
+use core::mem;
+
+use crate::equivalent::Equivalent;
+use crate::PhfSet;
+
+/// A hash map whose keys are given by a static [`PhfSet`], but whose values
+/// are owned and can be modified at runtime.
+///
+/// The values are stored in a slice-like container `S`, typically an array
+/// `[V; N]` or a `Vec<V>`, with one value per key in the iteration order of
+/// the key set. Keys cannot be added to or removed from the map.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::examples::*;
+/// use quickphf::FixedKeyMap;
+///
+/// let mut digit_counts = FixedKeyMap::new(&DIGITS, [0; 10]);
+/// for digit in [3, 1, 4, 1, 5, 9, 2, 6, 5, 3, 5] {
+///     *digit_counts.get_mut(&digit).unwrap() += 1;
+/// }
+///
+/// assert_eq!(digit_counts.get(&5), Some(&3));
+/// assert_eq!(digit_counts.get(&0), Some(&0));
+/// assert!(digit_counts.insert(&10, 1).is_err());
+/// ```
+pub struct FixedKeyMap<K: 'static, V, S> {
+    keys: &'static PhfSet<K>,
+    values: S,
+    value_marker: PhantomData<V>,
+}
+
+impl<K, V, S> FixedKeyMap<K, V, S>
+where
+    S: AsRef<[V]> + AsMut<[V]>,
+{
+    /// Creates a new map with the given key set and values.
+    ///
+    /// The `i`-th value is associated to the `i`-th key yielded by
+    /// [`PhfSet::iter`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of values is not equal to the number of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let squares = DIGITS.iter().map(|&d| d * d).collect::<Vec<_>>();
+    /// let map = FixedKeyMap::new(&DIGITS, squares);
+    ///
+    /// assert_eq!(map.get(&7), Some(&49));
+    /// ```
+    pub fn new(keys: &'static PhfSet<K>, values: S) -> FixedKeyMap<K, V, S> {
+        assert_eq!(
+            keys.len(),
+            values.as_ref().len(),
+            "number of values does not match number of keys"
+        );
+
+        FixedKeyMap {
+            keys,
+            values,
+            value_marker: PhantomData,
+        }
+    }
+
+    /// Returns references to the key and value corresponding to the supplied
+    /// key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let map = FixedKeyMap::new(&PRIME_DIGITS, [true; 4]);
+    ///
+    /// assert_eq!(map.get_key_value(&7), Some((&7, &true)));
+    /// assert_eq!(map.get_key_value(&8), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Eq + Hash,
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        let idx = self.keys.position(key)?;
+        Some((&self.keys.as_slice()[idx], &self.values.as_ref()[idx]))
+    }
+
+    /// Returns a reference to the value corresponding to the key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let map = FixedKeyMap::new(&EVEN_DIGITS, ["even"; 5]);
+    ///
+    /// assert_eq!(map.get(&4), Some(&"even"));
+    /// assert_eq!(map.get(&5), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Eq + Hash,
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns a mutable reference to the value corresponding to the key, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let mut map = FixedKeyMap::new(&EVEN_DIGITS, [0; 5]);
+    /// *map.get_mut(&6).unwrap() += 10;
+    ///
+    /// assert_eq!(map.get(&6), Some(&10));
+    /// assert_eq!(map.get_mut(&7), None);
+    /// ```
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Eq + Hash,
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        let idx = self.keys.position(key)?;
+        Some(&mut self.values.as_mut()[idx])
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let map = FixedKeyMap::new(&PRIME_DIGITS, [(); 4]);
+    ///
+    /// assert!(map.contains_key(&2));
+    /// assert!(!map.contains_key(&4));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Eq + Hash,
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.keys.position(key).is_some()
+    }
+
+    /// Replaces the value associated to an existing key, returning the old
+    /// value.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`UnknownKeyError`] containing `value` if `key` is not part
+    /// of the key set of the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let mut map = FixedKeyMap::new(&PRIME_DIGITS, [0; 4]);
+    ///
+    /// assert_eq!(map.insert(&3, 9), Ok(0));
+    /// assert_eq!(map.insert(&3, 27), Ok(9));
+    ///
+    /// let error = map.insert(&4, 16).unwrap_err();
+    /// assert_eq!(error.into_value(), 16);
+    /// ```
+    pub fn insert<Q>(&mut self, key: &Q, value: V) -> Result<V, UnknownKeyError<V>>
+    where
+        K: Eq + Hash,
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        match self.get_mut(key) {
+            Some(slot) => Ok(mem::replace(slot, value)),
+            None => Err(UnknownKeyError { value }),
+        }
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// assert_eq!(FixedKeyMap::new(&DIGITS, [0; 10]).len(), 10);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Returns `true` if the map does not contain any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// assert!(FixedKeyMap::new(&EMPTY_SET, [0; 0]).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// An iterator visiting all key-value pairs in the iteration order of the
+    /// key set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let mut map = FixedKeyMap::new(&PRIME_DIGITS, [0; 4]);
+    /// map.insert(&5, 25).unwrap();
+    ///
+    /// let mut entries = map.iter().map(|(&k, &v)| (k, v)).collect::<Vec<_>>();
+    /// entries.sort();
+    ///
+    /// assert_eq!(&entries, &[(2, 0), (3, 0), (5, 25), (7, 0)]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            iter: self.keys.iter().zip(self.values.as_ref().iter()),
+        }
+    }
+
+    /// An iterator visiting all key-value pairs in the iteration order of the
+    /// key set, with mutable references to the values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let mut map = FixedKeyMap::new(&EVEN_DIGITS, [0; 5]);
+    /// for (key, value) in map.iter_mut() {
+    ///     *value = key / 2;
+    /// }
+    ///
+    /// assert_eq!(map.get(&8), Some(&4));
+    /// ```
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            iter: self.keys.iter().zip(self.values.as_mut().iter_mut()),
+        }
+    }
+
+    /// Returns the values of the map, in the iteration order of the key set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let map = FixedKeyMap::new(&EVEN_DIGITS, [1; 5]);
+    ///
+    /// assert_eq!(map.values().iter().sum::<i32>(), 5);
+    /// ```
+    pub fn values(&self) -> &[V] {
+        self.values.as_ref()
+    }
+
+    /// Consumes the map, returning the container of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::FixedKeyMap;
+    ///
+    /// let map = FixedKeyMap::new(&EVEN_DIGITS, vec![2; 5]);
+    ///
+    /// assert_eq!(map.into_values(), vec![2; 5]);
+    /// ```
+    pub fn into_values(self) -> S {
+        self.values
+    }
+}
+
+impl<K, V, S> fmt::Debug for FixedKeyMap<K, V, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+    S: AsRef<[V]> + AsMut<[V]>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K, V, S: Clone> Clone for FixedKeyMap<K, V, S> {
+    fn clone(&self) -> Self {
+        FixedKeyMap {
+            keys: self.keys,
+            values: self.values.clone(),
+            value_marker: PhantomData,
+        }
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a FixedKeyMap<K, V, S>
+where
+    S: AsRef<[V]> + AsMut<[V]>,
+{
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a mut FixedKeyMap<K, V, S>
+where
+    S: AsRef<[V]> + AsMut<[V]>,
+{
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> IterMut<'a, K, V> {
+        self.iter_mut()
+    }
+}
+
+/// The error returned when trying to insert a key that is not part of the key
+/// set of a [`FixedKeyMap`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownKeyError<V> {
+    value: V,
+}
+
+impl<V> UnknownKeyError<V> {
+    /// Returns the value that could not be inserted.
+    pub fn into_value(self) -> V {
+        self.value
+    }
+}
+
+impl<V> fmt::Display for UnknownKeyError<V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "key is not part of the fixed key set")
+    }
+}
+
+#[cfg(feature = "std")]
+impl<V: fmt::Debug> std::error::Error for UnknownKeyError<V> {}
+
+#[derive(Clone)]
+/// An iterator over the entries of a `FixedKeyMap`.
+pub struct Iter<'a, K: 'static, V: 'a> {
+    iter: core::iter::Zip<crate::set::Iter<'a, K>, core::slice::Iter<'a, V>>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for Iter<'a, K, V> {}
+
+/// A mutable iterator over the entries of a `FixedKeyMap`.
+pub struct IterMut<'a, K: 'static, V: 'a> {
+    iter: core::iter::Zip<crate::set::Iter<'a, K>, core::slice::IterMut<'a, V>>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for IterMut<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for IterMut<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{DIGITS, EMPTY_SET};
+
+    use super::*;
+
+    #[test]
+    #[should_panic]
+    fn test_wrong_number_of_values() {
+        FixedKeyMap::new(&DIGITS, [0; 9]);
+    }
+
+    #[test]
+    fn test_empty() {
+        let mut map = FixedKeyMap::new(&EMPTY_SET, [0u8; 0]);
+        assert_eq!(map.get(&0), None);
+        assert!(map.insert(&0, 1).is_err());
+        assert!(map.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<FixedKeyMap<u64, u64, [u64; 8]>>();
+    }
+}
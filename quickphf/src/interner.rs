@@ -0,0 +1,244 @@
+//! A static string interner constructed at compile time with perfect
+//! hashing.
+
+use crate::RawPhfMap;
+
+/// A dense identifier of a string in a [`PhfInterner`].
+///
+/// The symbols of an interner with `n` strings are numbered from `0` to
+/// `n - 1`, in the order in which the strings were supplied to
+/// `quickphf_codegen`. This numbering is stable, so symbols can be stored or
+/// transmitted as plain integers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Symbol(u32);
+
+impl Symbol {
+    /// Returns the symbol with the given identifier.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::interner::Symbol;
+    ///
+    /// assert_eq!(KEYWORDS.resolve(Symbol::from_u32(7)), "fn");
+    /// ```
+    pub const fn from_u32(id: u32) -> Symbol {
+        Symbol(id)
+    }
+
+    /// Returns the identifier of the symbol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(KEYWORDS.id("as").unwrap().as_u32(), 0);
+    /// ```
+    pub const fn as_u32(self) -> u32 {
+        self.0
+    }
+
+    /// Returns the identifier of the symbol as an index.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut counts = [0; 10];
+    /// counts[KEYWORDS.id("if").unwrap().index()] += 1;
+    /// ```
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// A static string interner constructed at compile time with perfect
+/// hashing.
+///
+/// Maps each string of a fixed vocabulary to a dense [`Symbol`] and back, both
+/// in constant time.
+#[derive(Debug)]
+pub struct PhfInterner {
+    symbols: RawPhfMap<&'static str, u32>,
+    strings: &'static [&'static str],
+}
+
+impl PhfInterner {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the interner—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        symbols: &'static [u32],
+        strings: &'static [&'static str],
+        free: &'static [u32],
+    ) -> PhfInterner {
+        PhfInterner {
+            symbols: RawPhfMap::new(seed, pilots_table, symbols, free),
+            strings,
+        }
+    }
+
+    /// Returns the symbol of the given string, if it is part of the
+    /// vocabulary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::interner::Symbol;
+    ///
+    /// assert_eq!(KEYWORDS.id("break"), Some(Symbol::from_u32(1)));
+    /// assert_eq!(KEYWORDS.id("goto"), None);
+    /// ```
+    pub fn id(&self, string: &str) -> Option<Symbol> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let id = *self.symbols.get(string);
+        if self.strings[id as usize] == string {
+            Some(Symbol(id))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the string of the given symbol.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the symbol does not belong to this interner, i.e., if its
+    /// identifier is not less than [`len`](PhfInterner::len).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let symbol = KEYWORDS.id("crate").unwrap();
+    /// assert_eq!(KEYWORDS.resolve(symbol), "crate");
+    /// ```
+    pub fn resolve(&self, symbol: Symbol) -> &'static str {
+        self.strings[symbol.index()]
+    }
+
+    /// Returns the string of the given symbol, or `None` if the symbol does
+    /// not belong to this interner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::interner::Symbol;
+    ///
+    /// assert_eq!(KEYWORDS.get(Symbol::from_u32(2)), Some("const"));
+    /// assert_eq!(KEYWORDS.get(Symbol::from_u32(10)), None);
+    /// ```
+    pub fn get(&self, symbol: Symbol) -> Option<&'static str> {
+        self.strings.get(symbol.index()).copied()
+    }
+
+    /// Returns the number of strings in the interner.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(KEYWORDS.len(), 10);
+    /// assert_eq!(EMPTY_INTERNER.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if the interner does not contain any strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!KEYWORDS.is_empty());
+    /// assert!(EMPTY_INTERNER.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.strings.is_empty()
+    }
+
+    /// An iterator visiting all symbols and their strings in order of their
+    /// identifiers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let (symbol, keyword) = KEYWORDS.iter().nth(3).unwrap();
+    ///
+    /// assert_eq!(symbol.as_u32(), 3);
+    /// assert_eq!(keyword, "continue");
+    /// ```
+    pub fn iter(&self) -> Iter<'_> {
+        Iter {
+            strings: self.strings.iter().enumerate(),
+        }
+    }
+}
+
+impl<'a> IntoIterator for &'a PhfInterner {
+    type Item = (Symbol, &'static str);
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+#[derive(Clone)]
+/// An iterator over the symbols and strings of a `PhfInterner`.
+pub struct Iter<'a> {
+    strings: core::iter::Enumerate<core::slice::Iter<'a, &'static str>>,
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = (Symbol, &'static str);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.strings
+            .next()
+            .map(|(id, string)| (Symbol(id as u32), *string))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.strings.size_hint()
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl<'a> core::iter::FusedIterator for Iter<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_INTERNER, KEYWORDS};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_INTERNER.id("Fitz"), None);
+        assert!(EMPTY_INTERNER.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for (symbol, keyword) in &KEYWORDS {
+            assert_eq!(KEYWORDS.id(keyword), Some(symbol));
+            assert_eq!(KEYWORDS.resolve(symbol), keyword);
+        }
+    }
+}
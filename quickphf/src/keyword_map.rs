@@ -0,0 +1,231 @@
+//! An immutable hash table of string keys constructed at compile time with
+//! perfect hashing, which hashes only a few bytes of each key.
+
+use crate::map::Iter;
+use crate::RawPhfMap;
+
+/// The largest number of byte positions which a [`KeywordPhfMap`] hashes.
+pub const MAX_POSITIONS: usize = 7;
+
+/// A type of string which can be a key of a [`KeywordPhfMap`].
+///
+/// It is implemented for `str` and `[u8]`.
+pub trait KeywordKey: Eq {
+    /// Returns the bytes of the string.
+    fn as_bytes(&self) -> &[u8];
+}
+
+impl KeywordKey for str {
+    fn as_bytes(&self) -> &[u8] {
+        str::as_bytes(self)
+    }
+}
+
+impl KeywordKey for [u8] {
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+}
+
+/// Returns the integer combining the length of `bytes` with the bytes at the
+/// given positions, which are taken to be zero past the end of `bytes`.
+///
+/// This is the only part of a key which is hashed by a [`KeywordPhfMap`].
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::keyword_map::project;
+///
+/// assert_eq!(project(b"while", &[0, 4]), 0x05_77_65);
+/// assert_eq!(project(b"if", &[0, 4]), 0x02_69_00);
+/// ```
+#[allow(clippy::cast_possible_truncation)]
+pub fn project(bytes: &[u8], positions: &[u16]) -> u64 {
+    positions.iter().fold(bytes.len() as u64, |acc, &pos| {
+        let byte = bytes.get(pos as usize).copied().unwrap_or(0);
+        (acc << 8) | u64::from(byte)
+    })
+}
+
+/// An immutable hash table of string keys constructed at compile time with
+/// perfect hashing, which hashes only a few bytes of each key.
+///
+/// Like the tables generated by GNU `gperf`, a `KeywordPhfMap` only hashes the
+/// length of a key together with its bytes at a few positions, which
+/// `quickphf_codegen` chooses so that they distinguish all of the keys of the
+/// map. This is much cheaper than hashing whole keys, which makes it well
+/// suited for recognizing keywords in a lexer. A lookup then compares the
+/// queried key with the single entry which it could match.
+#[derive(Debug)]
+pub struct KeywordPhfMap<K: ?Sized + 'static, V: 'static> {
+    raw_map: RawPhfMap<u64, (&'static K, V)>,
+    positions: &'static [u16],
+}
+
+impl<K: ?Sized, V> KeywordPhfMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        entries: &'static [(&'static K, V)],
+        free: &'static [u32],
+        positions: &'static [u16],
+    ) -> KeywordPhfMap<K, V> {
+        KeywordPhfMap {
+            raw_map: RawPhfMap::new(seed, pilots_table, entries, free),
+            positions,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(C_KEYWORDS.len(), 32);
+    /// assert_eq!(EMPTY_KEYWORD_MAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.raw_map.len()
+    }
+
+    /// Returns `true` if the map does not contain any entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!C_KEYWORDS.is_empty());
+    /// assert!(EMPTY_KEYWORD_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.raw_map.is_empty()
+    }
+
+    /// Returns the positions of the bytes which are hashed along with the
+    /// length of a key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(C_KEYWORDS.positions(), &[0, 2]);
+    /// ```
+    pub const fn positions(&self) -> &'static [u16] {
+        self.positions
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let longest = C_KEYWORDS.iter().map(|e| e.0.len()).max();
+    ///
+    /// assert_eq!(longest, Some(8));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, &'static K, V> {
+        Iter::new(self.raw_map.as_slice())
+    }
+}
+
+impl<K: KeywordKey + ?Sized, V> KeywordPhfMap<K, V> {
+    /// Returns the key stored in the map and a reference to its value, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(C_KEYWORDS.get_key_value("while"), Some(("while", &32)));
+    /// assert_eq!(C_KEYWORDS.get_key_value("whilst"), None);
+    /// ```
+    pub fn get_key_value(&self, key: &K) -> Option<(&'static K, &V)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let entry = self.raw_map.get(&project(key.as_bytes(), self.positions));
+        if entry.0 == key {
+            Some((entry.0, &entry.1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(C_KEYWORDS.get("int"), Some(&17));
+    /// assert_eq!(C_KEYWORDS.get("integer"), None);
+    /// assert_eq!(HTTP_METHODS.get(b"PATCH"), Some(&9));
+    /// ```
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(C_KEYWORDS.contains_key("typedef"));
+    /// assert!(!C_KEYWORDS.contains_key("class"));
+    /// ```
+    pub fn contains_key(&self, key: &K) -> bool {
+        self.get_key_value(key).is_some()
+    }
+}
+
+impl<'a, K: ?Sized, V> IntoIterator for &'a KeywordPhfMap<K, V> {
+    type Item = &'a (&'static K, V);
+    type IntoIter = Iter<'a, &'static K, V>;
+
+    fn into_iter(self) -> Iter<'a, &'static K, V> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{C_KEYWORDS, EMPTY_KEYWORD_MAP, HTTP_METHODS};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_KEYWORD_MAP.get("Verity"), None);
+    }
+
+    #[test]
+    fn test_every_key() {
+        for (key, value) in &C_KEYWORDS {
+            assert_eq!(C_KEYWORDS.get(key), Some(value));
+        }
+        for (key, value) in &HTTP_METHODS {
+            assert_eq!(HTTP_METHODS.get(key), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_near_misses() {
+        // Keys which agree with a keyword on the hashed bytes still miss.
+        assert_eq!(C_KEYWORDS.get("whilf"), None);
+        assert_eq!(C_KEYWORDS.get(""), None);
+        assert_eq!(HTTP_METHODS.get(b"GETS"), None);
+    }
+}
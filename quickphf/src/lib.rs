@@ -9,6 +9,14 @@
 //! The minimum supported Rust version is 1.56. This crate is `#![no_std]` and
 //! `#![forbid(unsafe_code)]`.
 //!
+//! ## Crate Features
+//!
+//! - `std` (disabled by default): Enables `OverlayPhfMap`, which allows a
+//!   static [`PhfMap`] to be modified at runtime.
+//! - `const-map` (disabled by default, requires Rust 1.61): Enables the
+//!   `const_map` module, which constructs small tables in `const` contexts
+//!   without a build script.
+//!
 //! ## Features
 //!
 //! - Provides 3 perfect hash function-backed data structures: [`PhfMap`] and [`PhfSet`],
@@ -23,6 +31,8 @@
 //!   modulo computations.
 //! - Very low memory usage: no unused capacity and less than a byte of overhead
 //!   per entry.
+//! - Tables can also be constructed at runtime without allocating, from
+//!   buffers supplied by the caller. See the [`build`] module.
 //!
 //! ## Example
 //!
@@ -48,6 +58,9 @@
 #![no_std]
 #![allow(clippy::unreadable_literal)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 #[doc(hidden)]
 pub mod examples;
 
@@ -61,10 +74,129 @@ pub mod map;
 #[doc(inline)]
 pub use crate::map::PhfMap;
 
+pub mod map_array;
+
+#[doc(inline)]
+pub use crate::map_array::PhfMapArray;
+
+pub mod borrowed_map;
+
+#[doc(inline)]
+pub use crate::borrowed_map::BorrowedPhfMap;
+
+pub mod soa_map;
+
+#[doc(inline)]
+pub use crate::soa_map::SoaPhfMap;
+
+pub mod blob_map;
+
+#[doc(inline)]
+pub use crate::blob_map::BlobPhfMap;
+
+pub mod static_map;
+
+#[doc(inline)]
+pub use crate::static_map::StaticMap;
+
+pub mod keyword_map;
+
+#[doc(inline)]
+pub use crate::keyword_map::KeywordPhfMap;
+
+pub mod normalized_map;
+
+#[doc(inline)]
+pub use crate::normalized_map::NormalizedPhfMap;
+
+pub mod alias_map;
+
+#[doc(inline)]
+pub use crate::alias_map::AliasPhfMap;
+
+pub mod arena;
+
+#[doc(inline)]
+pub use crate::arena::ArenaPhfMap;
+
+pub mod bimap;
+
+#[doc(inline)]
+pub use crate::bimap::PhfBiMap;
+
+pub mod dedup_map;
+
+#[doc(inline)]
+pub use crate::dedup_map::DedupPhfMap;
+
+pub mod multimap;
+
+#[doc(inline)]
+pub use crate::multimap::PhfMultiMap;
+
+pub mod ordered_map;
+
+#[doc(inline)]
+pub use crate::ordered_map::OrderedPhfMap;
+
+pub mod interner;
+
+#[doc(inline)]
+pub use crate::interner::PhfInterner;
+
+pub mod records;
+
+#[doc(inline)]
+pub use crate::records::PhfRecords;
+
+pub mod columns;
+
+#[doc(inline)]
+pub use crate::columns::PhfColumns;
+
+pub mod packed;
+
+#[doc(inline)]
+pub use crate::packed::PackedRawPhfMap;
+
+pub mod equivalent;
+
+#[doc(inline)]
+pub use crate::equivalent::Equivalent;
+
 pub mod set;
 
 #[doc(inline)]
 pub use crate::set::PhfSet;
 
+mod const_hash;
+
+#[cfg(feature = "const-map")]
+pub mod const_map;
+
+pub mod sorted_map;
+
+#[doc(inline)]
+pub use crate::sorted_map::SortedPhfMap;
+
+pub mod sorted_set;
+
+#[doc(inline)]
+pub use crate::sorted_set::SortedPhfSet;
+
+pub mod fixed_map;
+
+#[doc(inline)]
+pub use crate::fixed_map::FixedKeyMap;
+
+#[cfg(feature = "std")]
+pub mod overlay;
+
+#[cfg(feature = "std")]
+#[doc(inline)]
+pub use crate::overlay::OverlayPhfMap;
+
+pub mod build;
+
 #[doc(hidden)]
 pub mod shared;
@@ -1,8 +1,10 @@
 //! An immutable hash table constructed at compile time with perfect hashing.
-use core::{borrow::Borrow, hash::Hash};
+use core::hash::Hash;
 
 use core::fmt::Debug;
 
+use crate::const_hash::bytes_eq;
+use crate::equivalent::Equivalent;
 use crate::RawPhfMap;
 
 /// An immutable hash table constructed at compile time with perfect hashing.
@@ -39,16 +41,25 @@ impl<K, V> PhfMap<K, V> {
     /// ```
     pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
     where
-        K: Borrow<Q>,
-        Q: Hash + Eq + ?Sized,
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        let item = &self.entries()[self.position(key)?];
+        Some((&item.0, &item.1))
+    }
+
+    /// Returns the position of the entry with the given key in the iteration
+    /// order of the map, if present.
+    pub(crate) fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
     {
         if self.is_empty() {
             return None;
         }
 
-        let item = self.raw_map.get(key);
-        if item.0.borrow() == key {
-            Some((&item.0, &item.1))
+        let idx = self.raw_map.slot_of(key);
+        if key.equivalent(&self.entries()[idx].0) {
+            Some(idx)
         } else {
             None
         }
@@ -66,8 +77,7 @@ impl<K, V> PhfMap<K, V> {
     /// ```
     pub fn get<Q>(&self, key: &Q) -> Option<&V>
     where
-        K: Borrow<Q>,
-        Q: Hash + Eq + ?Sized,
+        Q: Hash + Equivalent<K> + ?Sized,
     {
         self.get_key_value(key).map(|e| e.1)
     }
@@ -85,8 +95,7 @@ impl<K, V> PhfMap<K, V> {
     /// ```
     pub fn get_key<Q>(&self, key: &Q) -> Option<&K>
     where
-        K: Borrow<Q>,
-        Q: Hash + Eq + ?Sized,
+        Q: Hash + Equivalent<K> + ?Sized,
     {
         self.get_key_value(key).map(|e| e.0)
     }
@@ -102,8 +111,7 @@ impl<K, V> PhfMap<K, V> {
     /// ```
     pub fn contains_key<Q>(&self, key: &Q) -> bool
     where
-        K: Borrow<Q>,
-        Q: Hash + Eq + ?Sized,
+        Q: Hash + Equivalent<K> + ?Sized,
     {
         self.get_key_value(key).is_some()
     }
@@ -136,6 +144,11 @@ impl<K, V> PhfMap<K, V> {
         self.raw_map.is_empty()
     }
 
+    /// Returns the underlying slice of entries in codomain order.
+    pub(crate) const fn entries(&self) -> &'static [(K, V)] {
+        self.raw_map.as_slice()
+    }
+
     /// An iterator visiting all key-value pairs in arbitrary order.
     ///   
     /// # Examples
@@ -235,12 +248,115 @@ where
 {
 }
 
+impl<V> PhfMap<&'static str, V> {
+    /// Returns a reference to the value corresponding to the string whose
+    /// UTF-8 encoding is `key`, if present, without first checking that `key`
+    /// is valid UTF-8.
+    ///
+    /// The bytes are hashed exactly like the string they encode, so protocol
+    /// tokens can be looked up straight from an input buffer. Bytes which are
+    /// not valid UTF-8 never match a key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let path = b"/static/logo.svg";
+    /// let extension = path.rsplit(|&b| b == b'.').next().unwrap();
+    ///
+    /// assert_eq!(CONTENT_TYPES.get_bytes(extension), Some(&"image/svg+xml"));
+    /// assert_eq!(CONTENT_TYPES.get_bytes(b"sv\xff"), None);
+    /// ```
+    pub fn get_bytes(&self, key: &[u8]) -> Option<&V> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let entry = &self.raw_map.as_slice()[self.raw_map.slot_of_str_bytes(key)];
+        if entry.0.as_bytes() == key {
+            Some(&entry.1)
+        } else {
+            None
+        }
+    }
+}
+
+macro_rules! impl_get_const {
+    ($($key:ty, $arg:ty => |$a:ident, $b:ident| $eq:expr),*) => {
+        $(
+            impl<V> PhfMap<$key, V> {
+                /// Returns a reference to the value corresponding to the key,
+                /// if present. Unlike [`get`](PhfMap::get), it can be called
+                /// in a `const` context.
+                ///
+                /// Before Rust 1.83, a `const` cannot refer to a `static`, so
+                /// the map has to be a `const` itself.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use quickphf::examples::*;
+                ///
+                /// const _: () = assert!(FOURTH_POWERS_TO_ROOTS.get_const(4096).is_some());
+                ///
+                /// const ROOT: i32 = match FOURTH_POWERS_TO_ROOTS.get_const(625) {
+                ///     Some(root) => *root,
+                ///     None => 0,
+                /// };
+                ///
+                /// assert_eq!([0u8; ROOT as usize].len(), 5);
+                /// ```
+                pub const fn get_const(&self, key: $arg) -> Option<&V> {
+                    if self.is_empty() {
+                        return None;
+                    }
+
+                    let entry = self.raw_map.get_const(key);
+                    let $a = entry.0;
+                    let $b = key;
+                    if $eq {
+                        Some(&entry.1)
+                    } else {
+                        None
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_get_const!(
+    &'static str, &str => |a, b| bytes_eq(a.as_bytes(), b.as_bytes()),
+    &'static [u8], &[u8] => |a, b| bytes_eq(a, b),
+    u8, u8 => |a, b| a == b,
+    u16, u16 => |a, b| a == b,
+    u32, u32 => |a, b| a == b,
+    u64, u64 => |a, b| a == b,
+    u128, u128 => |a, b| a == b,
+    usize, usize => |a, b| a == b,
+    i8, i8 => |a, b| a == b,
+    i16, i16 => |a, b| a == b,
+    i32, i32 => |a, b| a == b,
+    i64, i64 => |a, b| a == b,
+    i128, i128 => |a, b| a == b,
+    isize, isize => |a, b| a == b
+);
+
 #[derive(Clone)]
 /// An iterator over the entries of a `PhfMap`.
 pub struct Iter<'a, K: 'a, V: 'a> {
     iter: crate::raw_map::Iter<'a, (K, V)>,
 }
 
+impl<'a, K, V> Iter<'a, K, V> {
+    pub(crate) fn new(entries: &'a [(K, V)]) -> Iter<'a, K, V> {
+        Iter {
+            iter: crate::raw_map::Iter::new(entries),
+        }
+    }
+}
+
 impl<'a, K, V> Iterator for Iter<'a, K, V> {
     type Item = &'a (K, V);
 
@@ -260,7 +376,7 @@ impl<'a, K, V> core::iter::FusedIterator for Iter<'a, K, V> {}
 #[derive(Clone)]
 /// An iterator over the values of a `PhfMap`.
 pub struct Values<'a, K: 'a, V: 'a> {
-    iter: Iter<'a, K, V>,
+    pub(crate) iter: Iter<'a, K, V>,
 }
 
 impl<'a, K, V> Iterator for Values<'a, K, V> {
@@ -282,7 +398,7 @@ impl<'a, K, V> core::iter::FusedIterator for Values<'a, K, V> {}
 #[derive(Clone)]
 /// An iterator over the keys of a `PhfMap`.
 pub struct Keys<'a, K: 'a, V: 'a> {
-    iter: Iter<'a, K, V>,
+    pub(crate) iter: Iter<'a, K, V>,
 }
 
 impl<'a, K, V> Iterator for Keys<'a, K, V> {
@@ -303,10 +419,47 @@ impl<'a, K, V> core::iter::FusedIterator for Keys<'a, K, V> {}
 
 #[cfg(test)]
 mod tests {
-    use crate::examples::EMPTY_MAP;
+    extern crate std;
+
+    use core::sync::atomic::{AtomicU64, Ordering};
+    use std::thread;
+    use std::vec::Vec;
+
+    use crate::examples::{CONTENT_TYPES, EMPTY_MAP, FOURTH_POWERS_TO_ROOTS, FRUIT_COUNTERS};
 
     use super::*;
 
+    #[test]
+    fn test_get_const() {
+        for key in FOURTH_POWERS_TO_ROOTS.keys() {
+            assert_eq!(
+                FOURTH_POWERS_TO_ROOTS.get_const(*key),
+                FOURTH_POWERS_TO_ROOTS.get(key)
+            );
+            assert_eq!(FOURTH_POWERS_TO_ROOTS.get_const(key + 1), None);
+        }
+
+        for key in ["apple", "banana", "cherry", "grape", "kiwi"] {
+            let value = FRUIT_COUNTERS.get_const(key).map(|v| v as *const AtomicU64);
+            assert_eq!(
+                value,
+                FRUIT_COUNTERS.get(key).map(|v| v as *const AtomicU64)
+            );
+        }
+
+        assert_eq!(EMPTY_MAP.get_const("Burrich"), None);
+    }
+
+    #[test]
+    fn test_get_bytes() {
+        for (key, value) in CONTENT_TYPES.iter() {
+            assert_eq!(CONTENT_TYPES.get_bytes(key.as_bytes()), Some(value));
+            assert_eq!(CONTENT_TYPES.get_bytes(&key.as_bytes()[1..]), None);
+        }
+
+        assert_eq!(EMPTY_MAP.get_bytes(b"Burrich"), None);
+    }
+
     #[test]
     fn test_empty() {
         assert_eq!(EMPTY_MAP.get("Nosy"), None);
@@ -322,5 +475,34 @@ mod tests {
     fn test_sync() {
         fn assert_sync<T: Sync>() {}
         assert_sync::<PhfMap<u64, u64>>();
+        assert_sync::<PhfMap<&'static str, AtomicU64>>();
+    }
+
+    #[test]
+    fn test_atomic_counters() {
+        // A single entry always lands in slot 0, so the tables can be written
+        // out by hand.
+        static ENTRIES: [(&str, AtomicU64); 1] = [("hits", AtomicU64::new(0))];
+        static COUNTERS: PhfMap<&str, AtomicU64> = PhfMap::new(0, &[0], &ENTRIES, &[]);
+
+        let handles: Vec<_> = (0..8)
+            .map(|_| {
+                thread::spawn(|| {
+                    for _ in 0..1000 {
+                        COUNTERS
+                            .get("hits")
+                            .unwrap()
+                            .fetch_add(1, Ordering::Relaxed);
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
+        }
+
+        assert_eq!(COUNTERS.get("hits").unwrap().load(Ordering::Relaxed), 8000);
+        assert!(COUNTERS.get("misses").is_none());
     }
 }
@@ -0,0 +1,283 @@
+//! An immutable hash table constructed at compile time with perfect hashing,
+//! which stores its tables inline.
+
+use core::hash::Hash;
+
+use quickdiv::DivisorU64;
+
+use crate::equivalent::Equivalent;
+use crate::map::{Iter, Keys, Values};
+use crate::shared::{get_slot, hash_key};
+
+/// An immutable hash table constructed at compile time with perfect hashing,
+/// which stores its tables inline.
+///
+/// Unlike a [`PhfMap`](crate::PhfMap), which refers to its tables through
+/// `&'static` slices, a `PhfMapArray` owns arrays of `N` entries, `B` pilot
+/// values and `F` free slots. It can thus be returned from a `const fn` or
+/// embedded by value in other `const` data structures.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::examples::*;
+/// use quickphf::PhfMapArray;
+///
+/// struct Config {
+///     retries: u32,
+///     squares: PhfMapArray<u32, u32, 5, 5, 2>,
+/// }
+///
+/// const CONFIG: Config = Config {
+///     retries: 3,
+///     squares: SQUARES_ARRAY,
+/// };
+///
+/// assert_eq!(CONFIG.squares.get(&4), Some(&16));
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct PhfMapArray<K, V, const N: usize, const B: usize, const F: usize> {
+    codomain_len: DivisorU64,
+    buckets: DivisorU64,
+    seed: u64,
+
+    pilots_table: [u16; B],
+    entries: [(K, V); N],
+    free: [u32; F],
+}
+
+impl<K, V, const N: usize, const B: usize, const F: usize> PhfMapArray<K, V, N, B, F> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: [u16; B],
+        entries: [(K, V); N],
+        free: [u32; F],
+    ) -> PhfMapArray<K, V, N, B, F> {
+        PhfMapArray {
+            codomain_len: DivisorU64::new((N + F) as u64),
+            buckets: DivisorU64::new(B as u64),
+            seed,
+
+            pilots_table,
+            entries,
+            free,
+        }
+    }
+
+    /// Returns references to the key and value corresponding to the supplied
+    /// key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(SQUARES_ARRAY.get_key_value(&3), Some((&3, &9)));
+    /// assert_eq!(SQUARES_ARRAY.get_key_value(&6), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if N == 0 {
+            return None;
+        }
+
+        let key_hash = hash_key(key, self.seed);
+        let idx = get_slot(
+            key_hash,
+            &self.pilots_table,
+            &self.free,
+            self.buckets,
+            self.codomain_len,
+            N,
+        );
+
+        let item = &self.entries[idx];
+        if key.equivalent(&item.0) {
+            Some((&item.0, &item.1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(SQUARES_ARRAY.get(&5), Some(&25));
+    /// assert_eq!(SQUARES_ARRAY.get(&0), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns a reference to the copy of the key stored in the map, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(SQUARES_ARRAY.get_key(&1), Some(&1));
+    /// assert_eq!(SQUARES_ARRAY.get_key(&9), None);
+    /// ```
+    pub fn get_key<Q>(&self, key: &Q) -> Option<&K>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.0)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(SQUARES_ARRAY.contains_key(&2));
+    /// assert!(!SQUARES_ARRAY.contains_key(&4096));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(SQUARES_ARRAY.len(), 5);
+    /// ```
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Returns `true` if the map does not contain any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!SQUARES_ARRAY.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut entries = SQUARES_ARRAY.iter().copied().collect::<Vec<_>>();
+    /// entries.sort();
+    ///
+    /// assert_eq!(&entries, &[(1, 1), (2, 4), (3, 9), (4, 16), (5, 25)]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(&self.entries)
+    }
+
+    /// An iterator visiting all stored keys in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut keys = SQUARES_ARRAY.keys().copied().collect::<Vec<_>>();
+    /// keys.sort();
+    ///
+    /// assert_eq!(&keys, &[1, 2, 3, 4, 5]);
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// An iterator visiting all stored values in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut values = SQUARES_ARRAY.values().copied().collect::<Vec<_>>();
+    /// values.sort();
+    ///
+    /// assert_eq!(&values, &[1, 4, 9, 16, 25]);
+    /// ```
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+}
+
+impl<'a, K, V, const N: usize, const B: usize, const F: usize> IntoIterator
+    for &'a PhfMapArray<K, V, N, B, F>
+{
+    type Item = &'a (K, V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<K, V, const N: usize, const B: usize, const F: usize> PartialEq for PhfMapArray<K, V, N, B, F>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.iter()
+            .all(|(k1, v1)| other.get(k1).map_or(false, |v2| *v1 == *v2))
+    }
+}
+
+impl<K, V, const N: usize, const B: usize, const F: usize> Eq for PhfMapArray<K, V, N, B, F>
+where
+    K: Eq + Hash,
+    V: Eq,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_MAP_ARRAY, SQUARES_ARRAY};
+
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_MAP_ARRAY.get("Kettricken"), None);
+        assert!(EMPTY_MAP_ARRAY.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_const_fn() {
+        const fn make() -> PhfMapArray<u32, u32, 5, 5, 2> {
+            SQUARES_ARRAY
+        }
+
+        assert_eq!(make(), SQUARES_ARRAY);
+    }
+}
@@ -0,0 +1,318 @@
+//! An immutable hash table constructed at compile time with perfect hashing,
+//! which maps each key to one or more values.
+
+use core::hash::Hash;
+
+use crate::equivalent::Equivalent;
+use crate::RawPhfMap;
+
+/// An immutable hash table constructed at compile time with perfect hashing,
+/// which maps each key to one or more values.
+///
+/// The values of all keys are stored in a single shared table, in which the
+/// values of each key form a contiguous slice, in the order in which they
+/// were supplied to `quickphf_codegen`.
+#[derive(Debug)]
+pub struct PhfMultiMap<K: 'static, V: 'static> {
+    raw_map: RawPhfMap<K, (K, u32)>,
+    values: &'static [V],
+}
+
+impl<K, V> PhfMultiMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        entries: &'static [(K, u32)],
+        free: &'static [u32],
+        values: &'static [V],
+    ) -> PhfMultiMap<K, V> {
+        PhfMultiMap {
+            raw_map: RawPhfMap::new(seed, pilots_table, entries, free),
+            values,
+        }
+    }
+
+    /// Returns the key stored in the map and the slice of its values, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(
+    ///     MIME_TYPES.get_key_values("xml"),
+    ///     Some((&"xml", &["application/xml", "text/xml"][..]))
+    /// );
+    /// assert_eq!(MIME_TYPES.get_key_values("exe"), None);
+    /// ```
+    pub fn get_key_values<Q>(&self, key: &Q) -> Option<(&K, &[V])>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let slot = self.raw_map.slot_of(key);
+        let entry = &self.raw_map.as_slice()[slot];
+        if key.equivalent(&entry.0) {
+            Some((&entry.0, self.values_at(slot)))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the slice of values corresponding to the key, which is empty
+    /// if the key is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(MIME_TYPES.get("png"), &["image/png"]);
+    /// assert!(MIME_TYPES.get("tar").is_empty());
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> &[V]
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_values(key).map_or(&[], |e| e.1)
+    }
+
+    /// An iterator visiting the values corresponding to the key in the order
+    /// in which they were supplied, which is empty if the key is not present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut types = MIME_TYPES.get_all("js");
+    ///
+    /// assert_eq!(types.next(), Some(&"application/javascript"));
+    /// assert_eq!(types.next(), Some(&"text/javascript"));
+    /// assert_eq!(types.next(), None);
+    /// ```
+    pub fn get_all<Q>(&self, key: &Q) -> core::slice::Iter<'_, V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get(key).iter()
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(MIME_TYPES.contains_key("ico"));
+    /// assert!(!MIME_TYPES.contains_key("gif"));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_values(key).is_some()
+    }
+
+    /// Returns the number of keys in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(MIME_TYPES.len(), 4);
+    /// assert_eq!(EMPTY_MULTIMAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.raw_map.len()
+    }
+
+    /// Returns the total number of values in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(MIME_TYPES.values_len(), 7);
+    /// ```
+    pub const fn values_len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns `true` if the map does not contain any keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!MIME_TYPES.is_empty());
+    /// assert!(EMPTY_MULTIMAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.raw_map.is_empty()
+    }
+
+    /// An iterator visiting all keys together with the slices of their
+    /// values, in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut counts = MIME_TYPES
+    ///     .iter()
+    ///     .map(|(ext, types)| (*ext, types.len()))
+    ///     .collect::<Vec<_>>();
+    /// counts.sort();
+    ///
+    /// assert_eq!(&counts, &[("ico", 2), ("js", 2), ("png", 1), ("xml", 2)]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            map: self,
+            slots: 0..self.len(),
+        }
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut extensions = MIME_TYPES.keys().copied().collect::<Vec<_>>();
+    /// extensions.sort();
+    ///
+    /// assert_eq!(&extensions, &["ico", "js", "png", "xml"]);
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// An iterator visiting all values in arbitrary order, in which the
+    /// values of each key are adjacent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let images = MIME_TYPES
+    ///     .values()
+    ///     .filter(|mime| mime.starts_with("image/"))
+    ///     .count();
+    ///
+    /// assert_eq!(images, 3);
+    /// ```
+    pub fn values(&self) -> core::slice::Iter<'_, V> {
+        self.values.iter()
+    }
+
+    /// Returns the values of the entry at the given position of the table.
+    fn values_at(&self, slot: usize) -> &[V] {
+        let entries = self.raw_map.as_slice();
+        let start = entries[slot].1 as usize;
+        let end = entries
+            .get(slot + 1)
+            .map_or(self.values.len(), |e| e.1 as usize);
+
+        &self.values[start..end]
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a PhfMultiMap<K, V> {
+    type Item = (&'a K, &'a [V]);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+#[derive(Clone)]
+/// An iterator over the keys of a `PhfMultiMap` and the slices of their
+/// values.
+pub struct Iter<'a, K: 'static, V: 'static> {
+    map: &'a PhfMultiMap<K, V>,
+    slots: core::ops::Range<usize>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a [V]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next().map(|slot| {
+            (
+                &self.map.raw_map.as_slice()[slot].0,
+                self.map.values_at(slot),
+            )
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slots.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for Iter<'a, K, V> {}
+
+#[derive(Clone)]
+/// An iterator over the keys of a `PhfMultiMap`.
+pub struct Keys<'a, K: 'static, V: 'static> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|e| e.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for Keys<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_MULTIMAP, MIME_TYPES};
+
+    #[test]
+    fn test_empty() {
+        assert!(EMPTY_MULTIMAP.get("Verity").is_empty());
+        assert!(EMPTY_MULTIMAP.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_slices_cover_values() {
+        let total = MIME_TYPES
+            .iter()
+            .map(|(_, values)| values.len())
+            .sum::<usize>();
+        assert_eq!(total, MIME_TYPES.values_len());
+
+        for (key, values) in &MIME_TYPES {
+            assert_eq!(MIME_TYPES.get(key), values);
+        }
+    }
+}
@@ -0,0 +1,293 @@
+//! An immutable hash table of string keys constructed at compile time with
+//! perfect hashing, whose keys are normalized before being looked up.
+
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+use crate::map::Iter;
+use crate::RawPhfMap;
+
+/// A normalization applied to the keys of a [`NormalizedPhfMap`], both by
+/// `quickphf_codegen` when constructing the map, and to the queried key when
+/// looking it up.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::normalized_map::KeyNormalizer;
+///
+/// /// Treats `-` and `_` as the same character.
+/// struct DashesAsUnderscores;
+///
+/// impl KeyNormalizer for DashesAsUnderscores {
+///     fn normalize<F: FnMut(char)>(key: &str, mut f: F) {
+///         key.chars().for_each(|c| f(if c == '-' { '_' } else { c }));
+///     }
+/// }
+/// ```
+pub trait KeyNormalizer {
+    /// Calls `f` with each character of the normalized form of `key`, in
+    /// order.
+    fn normalize<F: FnMut(char)>(key: &str, f: F);
+}
+
+/// Leaves keys unchanged.
+impl KeyNormalizer for () {
+    fn normalize<F: FnMut(char)>(key: &str, f: F) {
+        key.chars().for_each(f);
+    }
+}
+
+/// Ignores the ASCII case of keys, by converting them to ASCII lowercase.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AsciiCaseInsensitive;
+
+impl KeyNormalizer for AsciiCaseInsensitive {
+    fn normalize<F: FnMut(char)>(key: &str, f: F) {
+        key.chars().map(|c| c.to_ascii_lowercase()).for_each(f);
+    }
+}
+
+/// Converts keys to lowercase, as defined by [`char::to_lowercase`].
+///
+/// This only lowercases each character, and is not full Unicode case folding:
+/// keys which are only equal when folded, such as `"Straße"` and `"STRASSE"`,
+/// or `"ς"` and `"Σ"`, are still considered different.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UnicodeLowercase;
+
+impl KeyNormalizer for UnicodeLowercase {
+    fn normalize<F: FnMut(char)>(key: &str, f: F) {
+        key.chars().flat_map(char::to_lowercase).for_each(f);
+    }
+}
+
+/// Removes leading and trailing whitespace from keys, before applying the
+/// normalizer `N`.
+///
+/// Use `Trim(())` to only remove whitespace.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Trim<N>(pub N);
+
+impl<N: KeyNormalizer> KeyNormalizer for Trim<N> {
+    fn normalize<F: FnMut(char)>(key: &str, f: F) {
+        N::normalize(key.trim(), f);
+    }
+}
+
+#[doc(hidden)]
+/// A key which is hashed in its normalized form, without allocating.
+///
+/// This type is public because `quickphf_codegen` hashes the normalized keys
+/// in the same way—users should never need to use it.
+pub struct NormalizedKey<'a, N> {
+    key: &'a str,
+    normalizer: PhantomData<N>,
+}
+
+impl<'a, N> NormalizedKey<'a, N> {
+    pub fn new(key: &'a str) -> NormalizedKey<'a, N> {
+        NormalizedKey {
+            key,
+            normalizer: PhantomData,
+        }
+    }
+}
+
+impl<'a, N: KeyNormalizer> Hash for NormalizedKey<'a, N> {
+    /// Hashes the normalized key in the same way as a `str`, except that its
+    /// bytes are written in chunks of a fixed size, which only depend on the
+    /// normalized key.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let mut buffer = [0; 64];
+        let mut len = 0;
+
+        N::normalize(self.key, |c| {
+            if len + c.len_utf8() > buffer.len() {
+                state.write(&buffer[..len]);
+                len = 0;
+            }
+            len += c.encode_utf8(&mut buffer[len..]).len();
+        });
+
+        state.write(&buffer[..len]);
+        state.write_u8(0xff);
+    }
+}
+
+/// An immutable hash table of string keys constructed at compile time with
+/// perfect hashing, whose keys are normalized before being looked up.
+///
+/// The keys are stored in their normalized form, as given by the
+/// [`KeyNormalizer`] `N`, and the queried keys are normalized while being
+/// hashed and compared, without allocating. For example, with
+/// [`AsciiCaseInsensitive`], a lookup of `"Content-TYPE"` finds the entry of
+/// `"content-type"`.
+#[derive(Debug)]
+pub struct NormalizedPhfMap<V: 'static, N> {
+    raw_map: RawPhfMap<(), (&'static str, V)>,
+    normalizer: PhantomData<N>,
+}
+
+impl<V, N> NormalizedPhfMap<V, N> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        entries: &'static [(&'static str, V)],
+        free: &'static [u32],
+    ) -> NormalizedPhfMap<V, N> {
+        NormalizedPhfMap {
+            raw_map: RawPhfMap::new(seed, pilots_table, entries, free),
+            normalizer: PhantomData,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(HEADER_IDS.len(), 8);
+    /// assert_eq!(EMPTY_NORMALIZED_MAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.raw_map.len()
+    }
+
+    /// Returns `true` if the map does not contain any entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!HEADER_IDS.is_empty());
+    /// assert!(EMPTY_NORMALIZED_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.raw_map.is_empty()
+    }
+
+    /// An iterator visiting all entries, with their keys in normalized form,
+    /// in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(HEADER_IDS.iter().all(|e| e.0 == e.0.to_ascii_lowercase()));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, &'static str, V> {
+        Iter::new(self.raw_map.as_slice())
+    }
+}
+
+impl<V, N: KeyNormalizer> NormalizedPhfMap<V, N> {
+    /// Returns the normalized key stored in the map and a reference to its
+    /// value, if the normalized form of `key` is present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(
+    ///     HEADER_IDS.get_key_value("Content-TYPE"),
+    ///     Some(("content-type", &2))
+    /// );
+    /// assert_eq!(
+    ///     SETTINGS.get_key_value("  STRASSE\n"),
+    ///     Some(("strasse", &false))
+    /// );
+    /// ```
+    pub fn get_key_value(&self, key: &str) -> Option<(&'static str, &V)> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let slot = self.raw_map.slot_of(&NormalizedKey::<N>::new(key));
+        let entry = &self.raw_map.as_slice()[slot];
+
+        let mut stored = entry.0.chars();
+        let mut equal = true;
+        N::normalize(key, |c| equal &= stored.next() == Some(c));
+
+        if equal && stored.next().is_none() {
+            Some((entry.0, &entry.1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the normalized form
+    /// of `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(HEADER_IDS.get("ACCEPT"), Some(&0));
+    /// assert_eq!(HEADER_IDS.get("Accept-Encoding"), None);
+    /// assert_eq!(SETTINGS.get("Größe"), Some(&true));
+    /// ```
+    pub fn get(&self, key: &str) -> Option<&V> {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns `true` if the map contains the normalized form of `key`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(HEADER_IDS.contains_key("user-agent"));
+    /// assert!(!HEADER_IDS.contains_key("user agent"));
+    /// ```
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.get_key_value(key).is_some()
+    }
+}
+
+impl<'a, V, N> IntoIterator for &'a NormalizedPhfMap<V, N> {
+    type Item = &'a (&'static str, V);
+    type IntoIter = Iter<'a, &'static str, V>;
+
+    fn into_iter(self) -> Iter<'a, &'static str, V> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_NORMALIZED_MAP, HEADER_IDS, SETTINGS};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_NORMALIZED_MAP.get("Shrewd"), None);
+    }
+
+    #[test]
+    fn test_prefixes_and_extensions_miss() {
+        assert_eq!(HEADER_IDS.get("Content-Typ"), None);
+        assert_eq!(HEADER_IDS.get("Content-Types"), None);
+        assert_eq!(SETTINGS.get(" "), None);
+        // Keys are only lowercased, not case folded.
+        assert_eq!(SETTINGS.get("Straße"), None);
+    }
+
+    #[test]
+    fn test_long_keys() {
+        // Keys longer than the hashing buffer are hashed in several chunks.
+        let key = "X-A-Very-Long-Header-Name-Which-Does-Not-Fit-In-Sixty-Four-Bytes";
+        assert_eq!(HEADER_IDS.get(key), Some(&7));
+        assert_eq!(HEADER_IDS.get(&key[..key.len() - 1]), None);
+    }
+}
@@ -0,0 +1,287 @@
+//! An immutable hash table constructed at compile time with perfect hashing,
+//! which preserves the order of its entries.
+
+use core::hash::Hash;
+
+use crate::equivalent::Equivalent;
+use crate::map::{Iter, Keys, Values};
+use crate::RawPhfMap;
+
+/// An immutable hash table constructed at compile time with perfect hashing,
+/// which preserves the order of its entries.
+///
+/// The entries are stored in the order in which they were supplied to
+/// `quickphf_codegen`, and the perfect hash function maps each key to its
+/// position through an additional table of indices. Iteration thus follows
+/// the original order, and [`index_of`](OrderedPhfMap::index_of) returns the
+/// original position of a key, at the cost of four bytes per entry.
+#[derive(Debug)]
+pub struct OrderedPhfMap<K: 'static, V: 'static> {
+    indices: RawPhfMap<K, u32>,
+    entries: &'static [(K, V)],
+}
+
+impl<K, V> OrderedPhfMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        indices: &'static [u32],
+        entries: &'static [(K, V)],
+        free: &'static [u32],
+    ) -> OrderedPhfMap<K, V> {
+        OrderedPhfMap {
+            indices: RawPhfMap::new(seed, pilots_table, indices, free),
+            entries,
+        }
+    }
+
+    /// Returns the position of the given key in the original order of the
+    /// entries, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(PLANET_MOONS.index_of("mercury"), Some(0));
+    /// assert_eq!(PLANET_MOONS.index_of("earth"), Some(2));
+    /// assert_eq!(PLANET_MOONS.index_of("pluto"), None);
+    /// ```
+    pub fn index_of<Q>(&self, key: &Q) -> Option<usize>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let idx = *self.indices.value_of(key) as usize;
+        if key.equivalent(&self.entries[idx].0) {
+            Some(idx)
+        } else {
+            None
+        }
+    }
+
+    /// Returns references to the key and value corresponding to the supplied
+    /// key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(PLANET_MOONS.get_key_value("mars"), Some((&"mars", &2)));
+    /// assert_eq!(PLANET_MOONS.get_key_value("vulcan"), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.index_of(key).and_then(|idx| self.get_index(idx))
+    }
+
+    /// Returns a reference to the value corresponding to the key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(PLANET_MOONS.get("jupiter"), Some(&95));
+    /// assert_eq!(PLANET_MOONS.get("sun"), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns a reference to the copy of the key stored in the map, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(PLANET_MOONS.get_key("venus"), Some(&"venus"));
+    /// assert_eq!(PLANET_MOONS.get_key("ceres"), None);
+    /// ```
+    pub fn get_key<Q>(&self, key: &Q) -> Option<&K>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.0)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(PLANET_MOONS.contains_key("neptune"));
+    /// assert!(!PLANET_MOONS.contains_key("eris"));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.index_of(key).is_some()
+    }
+
+    /// Returns references to the key and value at the given position in the
+    /// original order of the entries, if it is in bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(PLANET_MOONS.get_index(3), Some((&"mars", &2)));
+    /// assert_eq!(PLANET_MOONS.get_index(8), None);
+    /// ```
+    pub fn get_index(&self, index: usize) -> Option<(&K, &V)> {
+        self.entries.get(index).map(|e| (&e.0, &e.1))
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(PLANET_MOONS.len(), 8);
+    /// assert_eq!(EMPTY_ORDERED_MAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map does not contain any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!PLANET_MOONS.is_empty());
+    /// assert!(EMPTY_ORDERED_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// An iterator visiting all key-value pairs in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut entries = PLANET_MOONS.iter();
+    ///
+    /// assert_eq!(entries.next(), Some(&("mercury", 0)));
+    /// assert_eq!(entries.next(), Some(&("venus", 0)));
+    /// assert_eq!(entries.next(), Some(&("earth", 1)));
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter::new(self.entries)
+    }
+
+    /// An iterator visiting all stored keys in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let planets = PLANET_MOONS.keys().copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     &planets,
+    ///     &["mercury", "venus", "earth", "mars", "jupiter", "saturn", "uranus", "neptune"]
+    /// );
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// An iterator visiting all stored values in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let moons = PLANET_MOONS.values().copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&moons, &[0, 0, 1, 2, 95, 146, 28, 16]);
+    /// ```
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a OrderedPhfMap<K, V> {
+    type Item = &'a (K, V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<K, V> PartialEq for OrderedPhfMap<K, V>
+where
+    K: PartialEq,
+    V: PartialEq,
+{
+    /// Two ordered maps are equal if they contain the same entries in the
+    /// same order.
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K, V> Eq for OrderedPhfMap<K, V>
+where
+    K: Eq,
+    V: Eq,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_ORDERED_MAP, PLANET_MOONS};
+
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_ORDERED_MAP.index_of("Molly"), None);
+        assert_eq!(EMPTY_ORDERED_MAP.get("Molly"), None);
+        assert!(EMPTY_ORDERED_MAP.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_index_of_matches_iteration_order() {
+        for (idx, (key, value)) in PLANET_MOONS.iter().enumerate() {
+            assert_eq!(PLANET_MOONS.index_of(key), Some(idx));
+            assert_eq!(PLANET_MOONS.get(key), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_sync() {
+        fn assert_sync<T: Sync>() {}
+        assert_sync::<OrderedPhfMap<u64, u64>>();
+    }
+}
@@ -0,0 +1,632 @@
+//! A hash map that layers runtime modifications on top of a static [`PhfMap`].
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::mem;
+use std::collections::{hash_map, HashMap, HashSet};
+use std::vec;
+use std::vec::Vec;
+
+use quickdiv::DivisorU64;
+
+use crate::build::build_in_place;
+use crate::shared::{get_slot, hash_key, table_sizes};
+use crate::PhfMap;
+
+/// A hash map that combines a static [`PhfMap`] with a small owned table of
+/// runtime changes.
+///
+/// Entries can be added, overridden or removed at runtime without copying the
+/// static map. Lookups first consult the table of changes and then fall back
+/// to the underlying perfect hash table. Once many changes have accumulated,
+/// [`compact`](OverlayPhfMap::compact) rebuilds all entries into a fresh
+/// owned perfect hash table.
+///
+/// Unlike those of a `PhfMap`, its lookups take keys which `K` can be borrowed
+/// as, rather than any [`Equivalent`](crate::Equivalent) type, since the
+/// changes are kept in a [`HashMap`].
+///
+/// Requires the `std` feature.
+#[derive(Debug)]
+pub struct OverlayPhfMap<K: 'static, V: 'static> {
+    base: Base<K, V>,
+    changes: HashMap<K, V>,
+    removed: HashSet<usize>,
+    len: usize,
+}
+
+#[derive(Debug)]
+enum Base<K: 'static, V: 'static> {
+    Static(&'static PhfMap<K, V>),
+    Owned(OwnedTable<K, V>),
+}
+
+impl<K, V> Base<K, V> {
+    fn entries(&self) -> &[(K, V)] {
+        match self {
+            Base::Static(map) => map.entries(),
+            Base::Owned(table) => &table.entries,
+        }
+    }
+
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self {
+            Base::Static(map) => map.position(key),
+            Base::Owned(table) => table.position(key),
+        }
+    }
+}
+
+/// A perfect hash table constructed at runtime, which owns its storage.
+#[derive(Debug)]
+struct OwnedTable<K, V> {
+    codomain_len: DivisorU64,
+    buckets: DivisorU64,
+    seed: u64,
+
+    pilots_table: Vec<u16>,
+    entries: Vec<(K, V)>,
+    free: Vec<u32>,
+}
+
+impl<K: Eq + Hash, V> OwnedTable<K, V> {
+    fn new(mut entries: Vec<(K, V)>) -> OwnedTable<K, V> {
+        let n = entries.len();
+        let (buckets, codomain_len) =
+            table_sizes(n).expect("too many entries for a perfect hash table");
+
+        let mut pilots_table = vec![0; buckets];
+        let mut free = vec![0; codomain_len - n];
+        let mut hashes = vec![0; n];
+        let mut slots = vec![0; codomain_len];
+
+        // The buffers have the required sizes, and the keys are distinct since
+        // they come from a single map, so the construction cannot fail.
+        let placement = build_in_place(
+            &mut entries,
+            |e| &e.0,
+            &mut pilots_table,
+            &mut free,
+            &mut hashes,
+            &mut slots,
+        )
+        .expect("failed to construct a perfect hash table");
+
+        OwnedTable {
+            codomain_len: DivisorU64::new(codomain_len as u64),
+            buckets: DivisorU64::new(buckets as u64),
+            seed: placement.seed,
+
+            pilots_table,
+            entries,
+            free,
+        }
+    }
+}
+
+impl<K, V> OwnedTable<K, V> {
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let key_hash = hash_key(key, self.seed);
+        let idx = get_slot(
+            key_hash,
+            &self.pilots_table,
+            &self.free,
+            self.buckets,
+            self.codomain_len,
+            self.entries.len(),
+        );
+
+        if self.entries[idx].0.borrow() == key {
+            Some(idx)
+        } else {
+            None
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> OverlayPhfMap<K, V> {
+    /// Creates a new overlay on top of the given static map, without any
+    /// changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    /// assert_eq!(map.get(&256), Some(&4));
+    /// ```
+    pub fn new(base: &'static PhfMap<K, V>) -> OverlayPhfMap<K, V> {
+        OverlayPhfMap {
+            base: Base::Static(base),
+            changes: HashMap::new(),
+            removed: HashSet::new(),
+            len: base.len(),
+        }
+    }
+
+    /// Returns references to the key and value corresponding to the supplied
+    /// key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    /// map.insert(14641, 11);
+    ///
+    /// assert_eq!(map.get_key_value(&81), Some((&81, &3)));
+    /// assert_eq!(map.get_key_value(&14641), Some((&14641, &11)));
+    /// assert_eq!(map.get_key_value(&8000), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(entry) = self.changes.get_key_value(key) {
+            return Some(entry);
+        }
+
+        let pos = self.base.position(key)?;
+        if self.removed.contains(&pos) {
+            return None;
+        }
+
+        let entry = &self.base.entries()[pos];
+        Some((&entry.0, &entry.1))
+    }
+
+    /// Returns a reference to the value corresponding to the key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    /// map.insert(2401, -7);
+    ///
+    /// assert_eq!(map.get(&2401), Some(&-7));
+    /// assert_eq!(map.get(&1729), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    /// map.remove(&10_000);
+    ///
+    /// assert!(map.contains_key(&6561));
+    /// assert!(!map.contains_key(&10_000));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Inserts a key-value pair into the map, overriding the value of the key
+    /// if it is already present.
+    ///
+    /// Returns the previous value of the key, or `None` if it was not present.
+    /// Values of the underlying table are shared, so a previous value which
+    /// comes from it is cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    ///
+    /// assert_eq!(map.insert(14641, 11), None);
+    /// assert_eq!(map.insert(16, -2), Some(2));
+    /// assert_eq!(map.insert(16, 2), Some(-2));
+    ///
+    /// assert_eq!(map.get(&16), Some(&2));
+    /// assert_eq!(map.len(), 11);
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Option<V>
+    where
+        V: Clone,
+    {
+        if let Some(current) = self.changes.get_mut(&key) {
+            return Some(mem::replace(current, value));
+        }
+
+        let previous = match self.base.position(&key) {
+            Some(pos) if !self.removed.remove(&pos) => Some(self.base.entries()[pos].1.clone()),
+            _ => None,
+        };
+        if previous.is_none() {
+            self.len += 1;
+        }
+
+        self.changes.insert(key, value);
+        previous
+    }
+
+    /// Removes a key from the map, returning its value if it was present.
+    ///
+    /// Keys of the underlying table are hidden by recording their position,
+    /// so the key type does not have to be `Clone`. Values of the underlying
+    /// table are shared, so a removed value which comes from it is cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    ///
+    /// assert_eq!(map.remove(&625), Some(5));
+    /// assert_eq!(map.remove(&625), None);
+    ///
+    /// assert_eq!(map.get(&625), None);
+    /// assert_eq!(map.len(), 9);
+    /// ```
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+        V: Clone,
+    {
+        let base_pos = self.base.position(key);
+
+        let removed = match self.changes.remove(key) {
+            Some(value) => Some(value),
+            None => base_pos
+                .filter(|pos| !self.removed.contains(pos))
+                .map(|pos| self.base.entries()[pos].1.clone()),
+        };
+
+        if removed.is_some() {
+            if let Some(pos) = base_pos {
+                self.removed.insert(pos);
+            }
+            self.len -= 1;
+        }
+
+        removed
+    }
+
+    /// Rebuilds the underlying table so that it contains all entries of the
+    /// map, and clears the table of changes.
+    ///
+    /// This is worth doing once the number of changes, as reported by
+    /// [`overlay_len`](OverlayPhfMap::overlay_len), becomes large.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    /// map.insert(14641, 11);
+    /// map.remove(&1);
+    /// assert_eq!(map.overlay_len(), 2);
+    ///
+    /// map.compact();
+    /// assert_eq!(map.overlay_len(), 0);
+    ///
+    /// assert_eq!(map.get(&14641), Some(&11));
+    /// assert_eq!(map.get(&1), None);
+    /// assert_eq!(map.len(), 10);
+    /// ```
+    pub fn compact(&mut self)
+    where
+        K: Clone,
+        V: Clone,
+    {
+        let mut entries = Vec::with_capacity(self.len);
+        for (key, value) in self.iter() {
+            if !self.changes.contains_key(key) {
+                entries.push((key.clone(), value.clone()));
+            }
+        }
+        entries.extend(self.changes.drain());
+        self.removed.clear();
+
+        self.base = Base::Owned(OwnedTable::new(entries));
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    /// map.insert(14641, 11);
+    /// map.insert(16, -2);
+    /// for key in [81, 256, 625, 1296, 2401, 4096, 6561, 10000] {
+    ///     map.remove(&key);
+    /// }
+    ///
+    /// let mut entries = map.iter().map(|(&k, &v)| (k, v)).collect::<Vec<_>>();
+    /// entries.sort();
+    ///
+    /// assert_eq!(&entries, &[(1, 1), (16, -2), (14641, 11)]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            base: self.base.entries().iter().enumerate(),
+            changes: self.changes.iter(),
+            changes_map: &self.changes,
+            removed: &self.removed,
+        }
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    /// map.insert(14641, 11);
+    ///
+    /// let mut keys = map.keys().copied().collect::<Vec<_>>();
+    /// keys.sort();
+    ///
+    /// let expected_keys = [1, 16, 81, 256, 625, 1296, 2401, 4096, 6561, 10000, 14641];
+    ///
+    /// assert_eq!(&keys, &expected_keys);
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    /// map.insert(1, 0);
+    ///
+    /// let mut values = map.values().copied().collect::<Vec<_>>();
+    /// values.sort();
+    ///
+    /// assert_eq!(&values, &[0, 2, 3, 4, 5, 6, 7, 8, 9, 10]);
+    /// ```
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+}
+
+impl<K, V> OverlayPhfMap<K, V> {
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&EMPTY_MAP);
+    /// assert_eq!(map.len(), 0);
+    ///
+    /// map.insert("Fitz", 1);
+    /// assert_eq!(map.len(), 1);
+    /// ```
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the map does not contain any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// assert!(OverlayPhfMap::new(&EMPTY_MAP).is_empty());
+    /// assert!(!OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of additions, overrides and removals which have
+    /// been made since the map was created or last compacted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::OverlayPhfMap;
+    ///
+    /// let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+    /// map.insert(1, -1);
+    /// map.insert(1, 1);
+    /// map.remove(&16);
+    ///
+    /// assert_eq!(map.overlay_len(), 2);
+    /// ```
+    pub fn overlay_len(&self) -> usize {
+        self.changes.len() + self.removed.len()
+    }
+}
+
+impl<'a, K: Eq + Hash, V> IntoIterator for &'a OverlayPhfMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of an `OverlayPhfMap`.
+pub struct Iter<'a, K: 'a, V: 'a> {
+    base: core::iter::Enumerate<core::slice::Iter<'a, (K, V)>>,
+    changes: hash_map::Iter<'a, K, V>,
+    changes_map: &'a HashMap<K, V>,
+    removed: &'a HashSet<usize>,
+}
+
+impl<'a, K, V> Clone for Iter<'a, K, V> {
+    fn clone(&self) -> Self {
+        Iter {
+            base: self.base.clone(),
+            changes: self.changes.clone(),
+            changes_map: self.changes_map,
+            removed: self.removed,
+        }
+    }
+}
+
+impl<'a, K: Eq + Hash, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (changes_map, removed) = (self.changes_map, self.removed);
+        if let Some((_, (k, v))) = self
+            .base
+            .by_ref()
+            .find(|(pos, e)| !removed.contains(pos) && !changes_map.contains_key(&e.0))
+        {
+            return Some((k, v));
+        }
+
+        self.changes.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let upper = self.base.len() + self.changes.len();
+        (0, Some(upper))
+    }
+}
+
+impl<'a, K: Eq + Hash, V> core::iter::FusedIterator for Iter<'a, K, V> {}
+
+/// An iterator over the keys of an `OverlayPhfMap`.
+#[derive(Clone)]
+pub struct Keys<'a, K: 'a, V: 'a> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K: Eq + Hash, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|e| e.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: Eq + Hash, V> core::iter::FusedIterator for Keys<'a, K, V> {}
+
+/// An iterator over the values of an `OverlayPhfMap`.
+#[derive(Clone)]
+pub struct Values<'a, K: 'a, V: 'a> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K: Eq + Hash, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|e| e.1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K: Eq + Hash, V> core::iter::FusedIterator for Values<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_MAP, FOURTH_POWERS_TO_ROOTS};
+
+    use super::*;
+
+    #[test]
+    fn test_tombstone_then_reinsert() {
+        let mut map = OverlayPhfMap::new(&FOURTH_POWERS_TO_ROOTS);
+
+        assert_eq!(map.remove(&81), Some(3));
+        assert_eq!(map.insert(81, -3), None);
+        assert_eq!(map.get(&81), Some(&-3));
+        assert_eq!(map.len(), 10);
+
+        assert_eq!(map.insert(7, 7), None);
+        assert_eq!(map.remove(&7), Some(7));
+        assert_eq!(map.overlay_len(), 1);
+        assert_eq!(map.iter().count(), map.len());
+
+        assert_eq!(map.remove(&81), Some(-3));
+        assert_eq!(map.get(&81), None);
+        assert_eq!(map.insert(81, 3), None);
+        assert_eq!(map.len(), 10);
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut map = OverlayPhfMap::new(&EMPTY_MAP);
+        for (key, i) in ["a", "b", "c", "d", "e", "f", "g"].iter().zip(0..) {
+            map.insert(*key, i);
+        }
+        map.remove("c");
+        map.compact();
+
+        assert_eq!(map.len(), 6);
+        assert_eq!(map.get("g"), Some(&6));
+        assert_eq!(map.get("c"), None);
+
+        map.remove("a");
+        map.compact();
+        assert_eq!(map.len(), 5);
+        assert_eq!(map.iter().count(), 5);
+        assert_eq!(map.get("a"), None);
+        assert_eq!(map.get("b"), Some(&1));
+    }
+}
@@ -0,0 +1,265 @@
+//! An immutable hash table constructed at compile time with perfect hashing
+//! which does not store its keys, and packs its small values into as few bits
+//! as possible.
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::marker::PhantomData;
+
+use crate::RawPhfMap;
+
+/// A type whose values can be stored in a [`PackedRawPhfMap`], by converting
+/// them to and from small unsigned integers.
+///
+/// It is implemented for `bool`, `u8`, `u16` and `u32`, and can be
+/// implemented for field-less enums by mapping their variants to distinct
+/// integers.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::packed::PackedValue;
+///
+/// #[derive(Clone, Copy)]
+/// enum Signal {
+///     Red,
+///     Yellow,
+///     Green,
+/// }
+///
+/// impl PackedValue for Signal {
+///     fn to_bits(self) -> u32 {
+///         self as u32
+///     }
+///
+///     fn from_bits(bits: u32) -> Signal {
+///         match bits {
+///             0 => Signal::Red,
+///             1 => Signal::Yellow,
+///             _ => Signal::Green,
+///         }
+///     }
+/// }
+/// ```
+pub trait PackedValue: Copy {
+    /// Returns the integer representing the value.
+    fn to_bits(self) -> u32;
+
+    /// Returns the value represented by the integer, which is always the
+    /// result of a call to [`to_bits`](PackedValue::to_bits).
+    fn from_bits(bits: u32) -> Self;
+}
+
+impl PackedValue for bool {
+    fn to_bits(self) -> u32 {
+        u32::from(self)
+    }
+
+    fn from_bits(bits: u32) -> bool {
+        bits != 0
+    }
+}
+
+macro_rules! impl_packed_value {
+    ($($t:ty),*) => {
+        $(
+            impl PackedValue for $t {
+                fn to_bits(self) -> u32 {
+                    u32::from(self)
+                }
+
+                #[allow(clippy::cast_possible_truncation)]
+                fn from_bits(bits: u32) -> $t {
+                    bits as $t
+                }
+            }
+        )*
+    };
+}
+
+impl_packed_value!(u8, u16, u32);
+
+/// An immutable hash table constructed at compile time with perfect hashing
+/// which does not store its keys, and packs its small values into as few bits
+/// as possible.
+///
+/// Each value takes up exactly as many bits as the largest of them, as
+/// computed by `quickphf_codegen`, so that a table of four-valued enums uses a
+/// quarter of a byte per entry. Values are returned by copy.
+#[derive(Debug)]
+pub struct PackedRawPhfMap<K, V> {
+    raw_map: RawPhfMap<K, ()>,
+    bits: u32,
+    words: &'static [u64],
+    value_marker: PhantomData<V>,
+}
+
+impl<K, V> PackedRawPhfMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        slots: &'static [()],
+        free: &'static [u32],
+        bits: u32,
+        words: &'static [u64],
+    ) -> PackedRawPhfMap<K, V> {
+        PackedRawPhfMap {
+            raw_map: RawPhfMap::new(seed, pilots_table, slots, free),
+            bits,
+            words,
+            value_marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(LETTER_POSITIONS.len(), 26);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.raw_map.len()
+    }
+
+    /// Returns `true` if the map does not contain any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!LETTER_POSITIONS.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.raw_map.is_empty()
+    }
+
+    /// Returns the number of bits taken up by each value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(LETTER_POSITIONS.bits(), 5);
+    /// assert_eq!(ELEMENT_STATES.bits(), 2);
+    /// ```
+    pub const fn bits(&self) -> u32 {
+        self.bits
+    }
+}
+
+impl<K, V: PackedValue> PackedRawPhfMap<K, V> {
+    /// Returns the value matching the given key.
+    ///
+    /// If `key` is not one of the keys that was used when constructing the map,
+    /// `get` will silently return an arbitrary value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `PackedRawPhfMap` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(LETTER_POSITIONS.get("q"), 17);
+    /// assert_eq!(ELEMENT_STATES.get("Hg"), State::Liquid);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> V
+    where
+        K: Borrow<Q>,
+        Q: Hash + ?Sized,
+    {
+        self.value_at(self.raw_map.slot_of(key))
+    }
+
+    /// An iterator visiting all the values stored in the map in an arbitrary
+    /// order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let gases = ELEMENT_STATES.iter().filter(|&s| s == State::Gas).count();
+    ///
+    /// assert_eq!(gases, 8);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            map: self,
+            slots: 0..self.len(),
+        }
+    }
+
+    /// Returns the value at the given position of the table.
+    #[allow(clippy::cast_possible_truncation)]
+    fn value_at(&self, slot: usize) -> V {
+        if self.bits == 0 {
+            return V::from_bits(0);
+        }
+
+        let start = slot * self.bits as usize;
+        let (word, offset) = (start / 64, start % 64);
+
+        let mut bits = self.words[word] >> offset;
+        if offset + self.bits as usize > 64 {
+            bits |= self.words[word + 1] << (64 - offset);
+        }
+
+        V::from_bits((bits & ((1 << self.bits) - 1)) as u32)
+    }
+}
+
+#[derive(Clone)]
+/// An iterator over the values of a `PackedRawPhfMap`.
+pub struct Iter<'a, K, V> {
+    map: &'a PackedRawPhfMap<K, V>,
+    slots: core::ops::Range<usize>,
+}
+
+impl<'a, K, V: PackedValue> Iterator for Iter<'a, K, V> {
+    type Item = V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.slots.next().map(|slot| self.map.value_at(slot))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.slots.size_hint()
+    }
+}
+
+impl<'a, K, V: PackedValue> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V: PackedValue> core::iter::FusedIterator for Iter<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{State, ELEMENT_STATES, LETTER_POSITIONS};
+
+    #[test]
+    fn test_get() {
+        assert_eq!(ELEMENT_STATES.get("Br"), State::Liquid);
+        assert_eq!(ELEMENT_STATES.get("Ar"), State::Gas);
+        assert_eq!(ELEMENT_STATES.get("C"), State::Solid);
+    }
+
+    #[test]
+    fn test_values_across_words() {
+        // With 5 bits per value, some values straddle two words.
+        let letters = "abcdefghijklmnopqrstuvwxyz";
+        for (pos, letter) in (1..).zip(letters.split_terminator("").skip(1)) {
+            assert_eq!(LETTER_POSITIONS.get(letter), pos);
+        }
+        assert_eq!(LETTER_POSITIONS.iter().map(u32::from).sum::<u32>(), 351);
+    }
+}
@@ -6,7 +6,11 @@ use core::marker::PhantomData;
 
 use quickdiv::DivisorU64;
 
-use crate::shared::{get_bucket, get_index, hash_key, hash_pilot_value};
+use crate::const_hash::{
+    hash_bytes, hash_i128, hash_i16, hash_i32, hash_i64, hash_i8, hash_isize, hash_str,
+    hash_str_bytes, hash_u128, hash_u16, hash_u32, hash_u64, hash_u8, hash_usize,
+};
+use crate::shared::{get_slot, hash_key};
 
 /// An immutable hash table constructed at compile time with perfect hashing which does not store its keys.
 #[derive(Debug)]
@@ -73,17 +77,28 @@ impl<K, V> RawPhfMap<K, V> {
         K: Borrow<Q>,
         Q: Hash + ?Sized,
     {
-        let key_hash = hash_key(key, self.seed);
+        self.value_of(key)
+    }
 
-        let bucket = get_bucket(key_hash, self.buckets);
-        let pilot_hash = hash_pilot_value(self.pilots_table[bucket]);
-        let idx = get_index(key_hash, pilot_hash, self.codomain_len);
+    /// Returns a reference to the value to which the given key is mapped,
+    /// without comparing keys, for the tables which store and compare their
+    /// keys themselves.
+    pub(crate) fn value_of<Q: Hash + ?Sized>(&self, key: &Q) -> &V {
+        &self.values[self.slot_of(key)]
+    }
 
-        if idx < self.len() {
-            &self.values[idx]
-        } else {
-            &self.values[self.free[idx - self.len()] as usize]
-        }
+    /// Returns the position in the table of values to which the given key is
+    /// mapped.
+    pub(crate) fn slot_of<Q: Hash + ?Sized>(&self, key: &Q) -> usize {
+        let key_hash = hash_key(key, self.seed);
+        get_slot(
+            key_hash,
+            self.pilots_table,
+            self.free,
+            self.buckets,
+            self.codomain_len,
+            self.len(),
+        )
     }
 
     /// Returns the number of elements in the map.
@@ -114,6 +129,11 @@ impl<K, V> RawPhfMap<K, V> {
         self.values.is_empty()
     }
 
+    /// Returns the underlying slice of values in codomain order.
+    pub(crate) const fn as_slice(&self) -> &'static [V] {
+        self.values
+    }
+
     /// An iterator visiting all the values stored in the map in an arbitrary order.
     ///
     /// # Examples
@@ -130,18 +150,125 @@ impl<K, V> RawPhfMap<K, V> {
     /// assert_eq!(&values, &[0, 0, 0, 0, 1, 1, 1, 1, 1, 1, 2, 2]);
     /// ```
     pub fn iter(&self) -> Iter<'_, V> {
-        Iter {
-            iter: self.values.iter(),
-        }
+        Iter::new(self.values)
+    }
+}
+
+impl<V> RawPhfMap<&'static str, V> {
+    /// Returns a reference to the value matching the string whose UTF-8
+    /// encoding is `key`, without first checking that `key` is valid UTF-8.
+    ///
+    /// The bytes are hashed exactly like the string they encode, so this is
+    /// equivalent to `get(core::str::from_utf8(key).unwrap())` whenever `key`
+    /// is valid UTF-8. As with [`get`](RawPhfMap::get), looking up an invalid
+    /// key silently returns an arbitrary value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `RawPhfMap` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let request = b"GET /calendar?month=oct HTTP/1.1";
+    ///
+    /// assert_eq!(*HOLIDAYS_PER_MONTH.get_bytes(&request[20..23]), 1);
+    /// ```
+    pub fn get_bytes(&self, key: &[u8]) -> &V {
+        &self.values[self.slot_of_str_bytes(key)]
+    }
+
+    /// Returns the position in the table of values to which the string whose
+    /// UTF-8 encoding is `key` is mapped.
+    pub(crate) fn slot_of_str_bytes(&self, key: &[u8]) -> usize {
+        let key_hash = hash_str_bytes(key, self.seed);
+        get_slot(
+            key_hash,
+            self.pilots_table,
+            self.free,
+            self.buckets,
+            self.codomain_len,
+            self.len(),
+        )
     }
 }
 
+macro_rules! impl_get_const {
+    ($($key:ty, $arg:ty => $hash:ident),*) => {
+        $(
+            impl<V> RawPhfMap<$key, V> {
+                /// Returns a reference to the value matching the given key.
+                /// Unlike [`get`](RawPhfMap::get), it can be called in a
+                /// `const` context.
+                ///
+                /// As with `get`, looking up an invalid key silently returns
+                /// an arbitrary value. Before Rust 1.83, a `const` cannot
+                /// refer to a `static`, so the map has to be a `const` itself.
+                ///
+                /// # Panics
+                ///
+                /// Panics if the `RawPhfMap` is empty.
+                ///
+                /// # Examples
+                ///
+                /// ```
+                /// use quickphf::examples::*;
+                ///
+                /// const HOLIDAYS_IN_JULY: i32 = *HOLIDAYS_PER_MONTH.get_const("jul");
+                ///
+                /// assert_eq!(HOLIDAYS_IN_JULY, 1);
+                /// ```
+                pub const fn get_const(&self, key: $arg) -> &V {
+                    let key_hash = $hash(key, self.seed);
+                    let idx = get_slot(
+                        key_hash,
+                        self.pilots_table,
+                        self.free,
+                        self.buckets,
+                        self.codomain_len,
+                        self.len(),
+                    );
+
+                    &self.values[idx]
+                }
+            }
+        )*
+    };
+}
+
+impl_get_const!(
+    &'static str, &str => hash_str,
+    &'static [u8], &[u8] => hash_bytes,
+    u8, u8 => hash_u8,
+    u16, u16 => hash_u16,
+    u32, u32 => hash_u32,
+    u64, u64 => hash_u64,
+    u128, u128 => hash_u128,
+    usize, usize => hash_usize,
+    i8, i8 => hash_i8,
+    i16, i16 => hash_i16,
+    i32, i32 => hash_i32,
+    i64, i64 => hash_i64,
+    i128, i128 => hash_i128,
+    isize, isize => hash_isize
+);
+
 #[derive(Clone)]
 /// An iterator over the values of a `RawPhfMap`.
 pub struct Iter<'a, V: 'a> {
     iter: core::slice::Iter<'a, V>,
 }
 
+impl<'a, V> Iter<'a, V> {
+    pub(crate) fn new(values: &'a [V]) -> Iter<'a, V> {
+        Iter {
+            iter: values.iter(),
+        }
+    }
+}
+
 impl<'a, V> Iterator for Iter<'a, V> {
     type Item = &'a V;
 
@@ -170,5 +297,6 @@ mod tests {
     fn test_sync() {
         fn assert_sync<T: Sync>() {}
         assert_sync::<RawPhfMap<u64, u64>>();
+        assert_sync::<RawPhfMap<&'static str, core::sync::atomic::AtomicU64>>();
     }
 }
@@ -0,0 +1,240 @@
+//! An immutable table of records constructed at compile time, which can be
+//! looked up by one or more of their fields with perfect hashing.
+
+use core::hash::Hash;
+use core::marker::PhantomData;
+
+use crate::equivalent::Equivalent;
+use crate::RawPhfMap;
+
+/// A field of the records of type `T` by which a [`PhfRecords`] table is
+/// indexed.
+///
+/// Implementations are usually provided on unit structs, which are then passed
+/// to [`PhfRecords::by`] to select the index. The position of each index is
+/// emitted as a constant by `quickphf_codegen`, under the name given to
+/// `RecordsWriter::index`, and should be used as [`INDEX`](RecordKey::INDEX)
+/// rather than a literal.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::records::RecordKey;
+///
+/// pub struct Country {
+///     pub iso2: &'static str,
+///     pub name: &'static str,
+/// }
+///
+/// // Generated by `RecordsWriter::index_constants`.
+/// pub const ISO2: usize = 0;
+///
+/// pub struct Iso2;
+///
+/// impl RecordKey<Country> for Iso2 {
+///     type Key = str;
+///     const INDEX: usize = ISO2;
+///
+///     fn key(record: &Country) -> &str {
+///         record.iso2
+///     }
+/// }
+/// ```
+pub trait RecordKey<T> {
+    /// The type of the field.
+    type Key: Hash + Eq + ?Sized;
+
+    /// The position of the index among the indexes of the table, as emitted
+    /// by `RecordsWriter::index_constants` in `quickphf_codegen`.
+    const INDEX: usize;
+
+    /// Returns the field of the given record.
+    fn key(record: &T) -> &Self::Key;
+}
+
+/// An immutable table of records constructed at compile time, which can be
+/// looked up by one or more of their fields with perfect hashing.
+///
+/// The records are stored once, in the order in which they were supplied to
+/// `quickphf_codegen`. Each index is a separate perfect hash function which
+/// maps the values of a field to the positions of their records, so unlike a
+/// [`PhfMap`](crate::PhfMap), the keys are not stored twice.
+#[derive(Debug)]
+pub struct PhfRecords<T: 'static> {
+    records: &'static [T],
+    indexes: &'static [RawPhfMap<(), u32>],
+}
+
+impl<T> PhfRecords<T> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the table—users should never directly write calls to it.
+    pub const fn new(
+        records: &'static [T],
+        indexes: &'static [RawPhfMap<(), u32>],
+    ) -> PhfRecords<T> {
+        PhfRecords { records, indexes }
+    }
+
+    /// Returns the index of the table over the field `F`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`F::INDEX`](RecordKey::INDEX) is not the position of an
+    /// index of the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(COUNTRIES.by(Iso2).get("DE").unwrap().name, "Germany");
+    /// assert_eq!(COUNTRIES.by(Iso3).get("JPN").unwrap().name, "Japan");
+    /// ```
+    pub fn by<F: RecordKey<T>>(&self, _field: F) -> Index<'_, T, F> {
+        Index {
+            records: self.records,
+            positions: &self.indexes[F::INDEX],
+            field: PhantomData,
+        }
+    }
+
+    /// Returns the number of records in the table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(COUNTRIES.len(), 5);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.records.len()
+    }
+
+    /// Returns `true` if the table does not contain any records.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!COUNTRIES.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.records.is_empty()
+    }
+
+    /// Returns the records of the table as a slice, in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(COUNTRIES.as_slice()[0].iso3, "DEU");
+    /// ```
+    pub const fn as_slice(&self) -> &'static [T] {
+        self.records
+    }
+
+    /// An iterator visiting all records in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let names = COUNTRIES.iter().map(|c| c.name).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&names, &["Germany", "France", "Japan", "Brazil", "Canada"]);
+    /// ```
+    pub fn iter(&self) -> core::slice::Iter<'_, T> {
+        self.records.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a PhfRecords<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> core::slice::Iter<'a, T> {
+        self.iter()
+    }
+}
+
+/// An index of a [`PhfRecords`] table over the field `F`.
+pub struct Index<'a, T: 'static, F> {
+    records: &'static [T],
+    positions: &'a RawPhfMap<(), u32>,
+    field: PhantomData<F>,
+}
+
+impl<'a, T, F: RecordKey<T>> Index<'a, T, F> {
+    /// Returns a reference to the record whose field equals the given key, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(COUNTRIES.by(Iso2).get("BR").unwrap().iso3, "BRA");
+    /// assert!(COUNTRIES.by(Iso2).get("XX").is_none());
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&'static T>
+    where
+        Q: Hash + Equivalent<F::Key> + ?Sized,
+    {
+        if self.records.is_empty() {
+            return None;
+        }
+
+        let position = self.positions.as_slice()[self.positions.slot_of(key)];
+        let record = &self.records[position as usize];
+        if key.equivalent(F::key(record)) {
+            Some(record)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if the table contains a record whose field equals the
+    /// given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(COUNTRIES.by(Iso3).contains_key("CAN"));
+    /// assert!(!COUNTRIES.by(Iso3).contains_key("CA"));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<F::Key> + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{Iso2, Iso3, COUNTRIES};
+
+    #[test]
+    fn test_indexes_agree() {
+        for country in &COUNTRIES {
+            let by_iso2 = COUNTRIES.by(Iso2).get(country.iso2).unwrap();
+            let by_iso3 = COUNTRIES.by(Iso3).get(country.iso3).unwrap();
+            assert!(core::ptr::eq(by_iso2, country));
+            assert!(core::ptr::eq(by_iso3, country));
+        }
+    }
+
+    #[test]
+    fn test_keys_of_other_index() {
+        assert!(COUNTRIES.by(Iso2).get("DEU").is_none());
+        assert!(COUNTRIES.by(Iso3).get("DE").is_none());
+    }
+}
@@ -3,6 +3,7 @@ use core::hash::Hash;
 
 // TODO: Debug impls
 
+use crate::equivalent::Equivalent;
 use crate::RawPhfMap;
 
 /// An immutable set constructed at compile time with perfect hashing.
@@ -56,6 +57,11 @@ impl<K> PhfSet<K> {
         self.len() == 0
     }
 
+    /// Returns the underlying slice of elements in codomain order.
+    pub(crate) const fn as_slice(&self) -> &'static [K] {
+        self.raw_map.as_slice()
+    }
+
     /// Returns an iterator over the elements of the set in no particular order.
     ///   
     /// # Examples
@@ -76,6 +82,24 @@ impl<K> PhfSet<K> {
 }
 
 impl<K: Eq + Hash> PhfSet<K> {
+    /// Returns the position of the given element in the iteration order of
+    /// the set, if present.
+    pub(crate) fn position<Q>(&self, element: &Q) -> Option<usize>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let idx = self.raw_map.slot_of(element);
+        if element.equivalent(&self.as_slice()[idx]) {
+            Some(idx)
+        } else {
+            None
+        }
+    }
+
     /// Returns `true` if the set contains the given element.
     ///
     /// # Examples
@@ -85,9 +109,15 @@ impl<K: Eq + Hash> PhfSet<K> {
     ///
     /// assert!(PRIME_DIGITS.contains(&2));
     /// assert!(!PRIME_DIGITS.contains(&1));
+    ///
+    /// let header = String::from("upgrade");
+    /// assert!(HOP_BY_HOP_HEADERS.contains(header.as_str()));
     /// ```
-    pub fn contains(&self, element: &K) -> bool {
-        self.get(element).is_some()
+    pub fn contains<Q>(&self, element: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.position(element).is_some()
     }
 
     /// Returns a reference to the copy of the element stored in the set, if
@@ -101,17 +131,11 @@ impl<K: Eq + Hash> PhfSet<K> {
     /// assert_eq!(EVEN_DIGITS.get(&2), Some(&2));
     /// assert_eq!(EVEN_DIGITS.get(&3), None);
     /// ```
-    pub fn get(&self, element: &K) -> Option<&K> {
-        if self.is_empty() {
-            return None;
-        }
-
-        let result = self.raw_map.get(element);
-        if result == element {
-            Some(result)
-        } else {
-            None
-        }
+    pub fn get<Q>(&self, element: &Q) -> Option<&K>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.position(element).map(|idx| &self.as_slice()[idx])
     }
 
     /// Returns an iterator over the set difference in no particular order.
@@ -245,6 +269,36 @@ impl<K: Eq + Hash> PhfSet<K> {
     }
 }
 
+impl PhfSet<&'static str> {
+    /// Returns the copy of the string whose UTF-8 encoding is `element`
+    /// stored in the set, if present, without first checking that `element`
+    /// is valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let line = b"connection: keep-alive";
+    /// let name = line.split(|&b| b == b':').next().unwrap();
+    ///
+    /// assert_eq!(HOP_BY_HOP_HEADERS.get_bytes(name), Some("connection"));
+    /// assert_eq!(HOP_BY_HOP_HEADERS.get_bytes(b"host"), None);
+    /// ```
+    pub fn get_bytes(&self, element: &[u8]) -> Option<&'static str> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let result = self.as_slice()[self.raw_map.slot_of_str_bytes(element)];
+        if result.as_bytes() == element {
+            Some(result)
+        } else {
+            None
+        }
+    }
+}
+
 impl<'a, K> IntoIterator for &'a PhfSet<K> {
     type Item = &'a K;
     type IntoIter = Iter<'a, K>;
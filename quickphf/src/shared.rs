@@ -8,18 +8,148 @@ pub fn hash_key<K: Hash + ?Sized>(key: &K, seed: u64) -> u64 {
 }
 
 #[inline]
-pub fn hash_pilot_value(pilot_value: u16) -> u64 {
+pub const fn hash_pilot_value(pilot_value: u16) -> u64 {
     /// Multiplicative constant from `fxhash`.
     const K: u64 = 0x517cc1b727220a95;
     (pilot_value as u64).wrapping_mul(K)
 }
 
 #[inline]
-pub fn get_bucket(key_hash: u64, buckets: quickdiv::DivisorU64) -> usize {
-    (key_hash % buckets) as usize
+pub const fn get_bucket(key_hash: u64, buckets: quickdiv::DivisorU64) -> usize {
+    buckets.rem_of(key_hash) as usize
 }
 
 #[inline]
-pub fn get_index(key_hash: u64, pilot_hash: u64, codomain_len: quickdiv::DivisorU64) -> usize {
-    ((key_hash ^ pilot_hash) % codomain_len) as usize
+pub const fn get_index(
+    key_hash: u64,
+    pilot_hash: u64,
+    codomain_len: quickdiv::DivisorU64,
+) -> usize {
+    codomain_len.rem_of(key_hash ^ pilot_hash) as usize
+}
+
+/// Returns the position in the table of entries to which a key with the given
+/// hash is mapped.
+#[inline]
+pub const fn get_slot(
+    key_hash: u64,
+    pilots_table: &[u16],
+    free: &[u32],
+    buckets: quickdiv::DivisorU64,
+    codomain_len: quickdiv::DivisorU64,
+    len: usize,
+) -> usize {
+    let bucket = get_bucket(key_hash, buckets);
+    let pilot_hash = hash_pilot_value(pilots_table[bucket]);
+    let idx = get_index(key_hash, pilot_hash, codomain_len);
+
+    if idx < len {
+        idx
+    } else {
+        free[idx - len] as usize
+    }
+}
+
+const fn const_ilog2(n: u64) -> u64 {
+    (u64::BITS - 1 - n.leading_zeros()) as u64
+}
+
+const fn div_ceil(a: u64, b: u64) -> u64 {
+    (a + b - 1) / b
+}
+
+/// Returns the number of buckets and the size of the codomain of the perfect
+/// hash function for a table with `n` entries, or `None` if no such table can
+/// be constructed on this target.
+pub const fn table_sizes(n: usize) -> Option<(usize, usize)> {
+    if n == 0 {
+        // Both have to be non-zero so that they can be used as divisors.
+        return Some((1, 1));
+    }
+
+    // The slots of the codomain are indexed with `u32`s, with `u32::MAX`
+    // reserved as a sentinel during construction. This also bounds the products below well
+    // within the range of a `u64`.
+    let n = n as u64;
+    if n >= u32::MAX as u64 {
+        return None;
+    }
+
+    let lg = const_ilog2(n);
+
+    // Corresponds to `c * n / lg` buckets with `c = 1.5 + 0.2 * lg`.
+    let buckets = if n > 1 {
+        div_ceil((15 + 2 * lg) * n, 10 * lg)
+    } else {
+        1
+    };
+
+    // Corresponds to a load factor of `alpha = 0.99 - 0.001 * lg`, rounded up
+    // to an odd number.
+    let candidate = div_ceil(1000 * n, 990 - lg);
+    let codomain_len = candidate + (1 - candidate % 2);
+
+    // There are fewer buckets than slots, so both fit if the slots do.
+    if codomain_len > usize::MAX as u64 {
+        return None;
+    }
+
+    Some((buckets as usize, codomain_len as usize))
+}
+
+/// Returns the magic number with which [`rem_with_magic`] computes remainders
+/// modulo `divisor`.
+#[inline]
+pub const fn magic_for(divisor: u64) -> u128 {
+    (u128::MAX / divisor as u128).wrapping_add(1)
+}
+
+/// Returns `n % divisor`, using the magic number computed by [`magic_for`]
+/// to replace the division by multiplications.
+///
+/// This is Lemire's `fastmod` algorithm, which is also used by `quickdiv`.
+#[inline]
+#[allow(clippy::cast_possible_truncation)]
+pub const fn rem_with_magic(n: u64, magic: u128, divisor: u64) -> u64 {
+    let low_bits = magic.wrapping_mul(n as u128);
+
+    let bottom_half = ((low_bits & u64::MAX as u128) * divisor as u128) >> 64;
+    let top_half = (low_bits >> 64) * divisor as u128;
+
+    ((bottom_half + top_half) >> 64) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{magic_for, rem_with_magic};
+    use crate::examples::month_number;
+
+    #[test]
+    fn test_rem_with_magic() {
+        let divisors = [
+            1,
+            2,
+            3,
+            7,
+            1000,
+            u64::from(u32::MAX),
+            u64::MAX - 1,
+            u64::MAX,
+        ];
+        let numerators = [0, 1, 6, 999, 12_345_678_901, u64::MAX - 1, u64::MAX];
+
+        for &d in &divisors {
+            let magic = magic_for(d);
+            for &n in &numerators {
+                assert_eq!(rem_with_magic(n, magic, d), n % d);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lookup_fn() {
+        assert_eq!(month_number("jan"), Some(&1));
+        assert_eq!(month_number("dec"), Some(&12));
+        assert_eq!(month_number("xyz"), None);
+    }
 }
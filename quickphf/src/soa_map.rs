@@ -0,0 +1,229 @@
+//! An immutable hash table constructed at compile time with perfect hashing,
+//! which stores its keys and its values in separate tables.
+
+use core::hash::Hash;
+
+use crate::equivalent::Equivalent;
+use crate::RawPhfMap;
+
+/// An immutable hash table constructed at compile time with perfect hashing,
+/// which stores its keys and its values in separate tables.
+///
+/// Whereas a [`PhfMap`](crate::PhfMap) stores `(K, V)` entries, a
+/// `SoaPhfMap` keeps a table of keys and a table of values laid out in the
+/// same order, so that comparing the queried key only touches the table of
+/// keys. This makes lookups of absent keys cheaper when the values are large,
+/// and avoids the padding between keys and values of different alignments.
+#[derive(Debug)]
+pub struct SoaPhfMap<K: 'static, V: 'static> {
+    raw_map: RawPhfMap<K, K>,
+    values: &'static [V],
+}
+
+impl<K, V> SoaPhfMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        keys: &'static [K],
+        free: &'static [u32],
+        values: &'static [V],
+    ) -> SoaPhfMap<K, V> {
+        SoaPhfMap {
+            raw_map: RawPhfMap::new(seed, pilots_table, keys, free),
+            values,
+        }
+    }
+
+    /// Returns the key stored in the map and a reference to its value, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(STATUS_REASONS.get_key_value(&404), Some((&404, &"Not Found")));
+    /// assert_eq!(STATUS_REASONS.get_key_value(&418), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        if self.is_empty() {
+            return None;
+        }
+
+        let slot = self.raw_map.slot_of(key);
+        let stored = &self.raw_map.as_slice()[slot];
+        if key.equivalent(stored) {
+            Some((stored, &self.values[slot]))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(STATUS_REASONS.get(&200), Some(&"OK"));
+    /// assert_eq!(STATUS_REASONS.get(&299), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(STATUS_REASONS.contains_key(&503));
+    /// assert!(!STATUS_REASONS.contains_key(&504));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.get_key_value(key).is_some()
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(STATUS_REASONS.len(), 10);
+    /// assert_eq!(EMPTY_SOA_MAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.raw_map.len()
+    }
+
+    /// Returns `true` if the map does not contain any entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!STATUS_REASONS.is_empty());
+    /// assert!(EMPTY_SOA_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.raw_map.is_empty()
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let mut client_errors = STATUS_REASONS
+    ///     .iter()
+    ///     .filter(|&(&code, _)| (400..500).contains(&code))
+    ///     .map(|(_, &reason)| reason)
+    ///     .collect::<Vec<_>>();
+    /// client_errors.sort();
+    ///
+    /// assert_eq!(&client_errors, &["Bad Request", "Forbidden", "Not Found"]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            keys: self.raw_map.as_slice().iter(),
+            values: self.values.iter(),
+        }
+    }
+
+    /// An iterator visiting all keys in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(STATUS_REASONS.keys().filter(|&&code| code >= 500).count(), 3);
+    /// ```
+    pub fn keys(&self) -> core::slice::Iter<'_, K> {
+        self.raw_map.as_slice().iter()
+    }
+
+    /// An iterator visiting all values in arbitrary order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(STATUS_REASONS.values().any(|&reason| reason == "Created"));
+    /// ```
+    pub fn values(&self) -> core::slice::Iter<'_, V> {
+        self.values.iter()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a SoaPhfMap<K, V> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+#[derive(Clone)]
+/// An iterator over the entries of a `SoaPhfMap`.
+pub struct Iter<'a, K: 'static, V: 'static> {
+    keys: core::slice::Iter<'a, K>,
+    values: core::slice::Iter<'a, V>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some((self.keys.next()?, self.values.next()?))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.keys.size_hint()
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for Iter<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{EMPTY_SOA_MAP, STATUS_REASONS};
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_SOA_MAP.get(&200), None);
+        assert!(EMPTY_SOA_MAP.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_iter_matches_get() {
+        assert_eq!(STATUS_REASONS.iter().len(), STATUS_REASONS.len());
+        for (key, value) in &STATUS_REASONS {
+            assert_eq!(STATUS_REASONS.get(key), Some(value));
+        }
+    }
+}
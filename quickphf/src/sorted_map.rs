@@ -0,0 +1,450 @@
+//! An immutable hash table constructed at compile time with perfect hashing,
+//! which also supports ordered queries on its keys.
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::ops::{Bound, RangeBounds};
+
+use crate::equivalent::Equivalent;
+use crate::PhfMap;
+
+/// An immutable hash table constructed at compile time with perfect hashing,
+/// which also supports ordered queries on its keys.
+///
+/// Besides the usual table of a [`PhfMap`], a `SortedPhfMap` stores the
+/// positions of its entries sorted by key, at the cost of four bytes per
+/// entry. Lookups by key still take constant time, while [`range`],
+/// [`first`], [`last`] and [`prefix`] use binary search over the sorted
+/// positions.
+///
+/// [`range`]: SortedPhfMap::range
+/// [`first`]: SortedPhfMap::first
+/// [`last`]: SortedPhfMap::last
+/// [`prefix`]: SortedPhfMap::prefix
+#[derive(Debug)]
+pub struct SortedPhfMap<K: 'static, V: 'static> {
+    map: PhfMap<K, V>,
+    sorted: &'static [u32],
+}
+
+impl<K, V> SortedPhfMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        entries: &'static [(K, V)],
+        free: &'static [u32],
+        sorted: &'static [u32],
+    ) -> SortedPhfMap<K, V> {
+        SortedPhfMap {
+            map: PhfMap::new(seed, pilots_table, entries, free),
+            sorted,
+        }
+    }
+
+    /// Returns the underlying [`PhfMap`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let map = ELEMENTS.as_map();
+    /// assert_eq!(map.get("neon"), Some(&10));
+    /// ```
+    pub const fn as_map(&self) -> &PhfMap<K, V> {
+        &self.map
+    }
+
+    /// Returns references to the key and value corresponding to the supplied
+    /// key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(ELEMENTS.get_key_value("boron"), Some((&"boron", &5)));
+    /// assert_eq!(ELEMENTS.get_key_value("sodium"), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.map.get_key_value(key)
+    }
+
+    /// Returns a reference to the value corresponding to the key, if present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(ELEMENTS.get("carbon"), Some(&6));
+    /// assert_eq!(ELEMENTS.get("iron"), None);
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.map.get(key)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(ELEMENTS.contains_key("oxygen"));
+    /// assert!(!ELEMENTS.contains_key("gold"));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    /// Returns the number of elements in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(ELEMENTS.len(), 10);
+    /// assert_eq!(EMPTY_SORTED_MAP.len(), 0);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the map does not contain any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!ELEMENTS.is_empty());
+    /// assert!(EMPTY_SORTED_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Returns the entry with the smallest key, if the map is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(ELEMENTS.first(), Some((&"beryllium", &4)));
+    /// assert_eq!(EMPTY_SORTED_MAP.first(), None);
+    /// ```
+    pub fn first(&self) -> Option<(&K, &V)> {
+        self.iter_sorted().next().map(|e| (&e.0, &e.1))
+    }
+
+    /// Returns the entry with the largest key, if the map is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(ELEMENTS.last(), Some((&"oxygen", &8)));
+    /// assert_eq!(EMPTY_SORTED_MAP.last(), None);
+    /// ```
+    pub fn last(&self) -> Option<(&K, &V)> {
+        self.iter_sorted().next_back().map(|e| (&e.0, &e.1))
+    }
+
+    /// An iterator visiting all key-value pairs in arbitrary order, which is
+    /// faster than [`iter_sorted`](SortedPhfMap::iter_sorted).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(ELEMENTS.iter().count(), 10);
+    /// ```
+    pub fn iter(&self) -> crate::map::Iter<'_, K, V> {
+        self.map.iter()
+    }
+
+    /// An iterator visiting all key-value pairs in increasing order of keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let elements = ELEMENTS.iter_sorted().map(|e| e.0).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&elements[..4], &["beryllium", "boron", "carbon", "fluorine"]);
+    /// ```
+    pub fn iter_sorted(&self) -> Iter<'_, K, V> {
+        self.iter_positions(0, self.sorted.len())
+    }
+
+    /// An iterator visiting all keys in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let elements = ELEMENTS.keys_sorted().copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&elements[6..], &["lithium", "neon", "nitrogen", "oxygen"]);
+    /// ```
+    pub fn keys_sorted(&self) -> Keys<'_, K, V> {
+        Keys {
+            iter: self.iter_sorted(),
+        }
+    }
+
+    /// An iterator visiting all values in increasing order of their keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let numbers = ELEMENTS.values_sorted().copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&numbers, &[4, 5, 6, 9, 2, 1, 3, 10, 7, 8]);
+    /// ```
+    pub fn values_sorted(&self) -> Values<'_, K, V> {
+        Values {
+            iter: self.iter_sorted(),
+        }
+    }
+
+    /// An iterator visiting, in increasing order, all key-value pairs whose
+    /// keys lie within the given range. An empty iterator is returned if the
+    /// start of the range is greater than its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let elements = ELEMENTS.range("c".."h").map(|e| e.0).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&elements, &["carbon", "fluorine"]);
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.partition_point(|key| key < start),
+            Bound::Excluded(start) => self.partition_point(|key| key <= start),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.partition_point(|key| key <= end),
+            Bound::Excluded(end) => self.partition_point(|key| key < end),
+            Bound::Unbounded => self.sorted.len(),
+        };
+
+        self.iter_positions(start, usize::max(start, end))
+    }
+
+    /// Returns the number of sorted positions whose keys satisfy `pred`,
+    /// which has to hold for a prefix of the sorted keys.
+    fn partition_point<Q, P>(&self, mut pred: P) -> usize
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        P: FnMut(&Q) -> bool,
+    {
+        let entries = self.map.entries();
+        self.sorted
+            .partition_point(|&pos| pred(entries[pos as usize].0.borrow()))
+    }
+
+    fn iter_positions(&self, start: usize, end: usize) -> Iter<'_, K, V> {
+        Iter {
+            entries: self.map.entries(),
+            positions: self.sorted[start..end].iter(),
+        }
+    }
+}
+
+impl<V> SortedPhfMap<&'static str, V> {
+    /// An iterator visiting, in increasing order, all key-value pairs whose
+    /// keys start with the given prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let elements = ELEMENTS.prefix("n").map(|e| e.0).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&elements, &["neon", "nitrogen"]);
+    /// assert_eq!(ELEMENTS.prefix("x").next(), None);
+    /// ```
+    pub fn prefix(&self, prefix: &str) -> Iter<'_, &'static str, V> {
+        let start = self.partition_point(|key: &str| key < prefix);
+        let len = self.sorted[start..]
+            .partition_point(|&pos| self.map.entries()[pos as usize].0.starts_with(prefix));
+
+        self.iter_positions(start, start + len)
+    }
+}
+
+impl<K, V> PartialEq for SortedPhfMap<K, V>
+where
+    K: Eq + Hash,
+    V: PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K, V> Eq for SortedPhfMap<K, V>
+where
+    K: Eq + Hash,
+    V: Eq,
+{
+}
+
+#[derive(Clone)]
+/// An iterator over the entries of a `SortedPhfMap` in increasing order of
+/// keys.
+pub struct Iter<'a, K: 'a, V: 'a> {
+    entries: &'a [(K, V)],
+    positions: core::slice::Iter<'a, u32>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = &'a (K, V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.positions
+            .next()
+            .map(|&pos| &self.entries[pos as usize])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Iter<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.positions
+            .next_back()
+            .map(|&pos| &self.entries[pos as usize])
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for Iter<'a, K, V> {}
+
+#[derive(Clone)]
+/// An iterator over the keys of a `SortedPhfMap` in increasing order.
+pub struct Keys<'a, K: 'a, V: 'a> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Keys<'a, K, V> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|t| &t.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Keys<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|t| &t.0)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Keys<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for Keys<'a, K, V> {}
+
+#[derive(Clone)]
+/// An iterator over the values of a `SortedPhfMap` in increasing order of
+/// their keys.
+pub struct Values<'a, K: 'a, V: 'a> {
+    iter: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+    type Item = &'a V;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|t| &t.1)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, K, V> DoubleEndedIterator for Values<'a, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|t| &t.1)
+    }
+}
+
+impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {}
+
+impl<'a, K, V> core::iter::FusedIterator for Values<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::{ELEMENTS, EMPTY_SORTED_MAP};
+
+    use super::*;
+
+    #[test]
+    fn test_empty() {
+        assert!(EMPTY_SORTED_MAP.iter_sorted().next().is_none());
+        assert!(EMPTY_SORTED_MAP.range("a".."z").next().is_none());
+        assert!(EMPTY_SORTED_MAP.prefix("").next().is_none());
+    }
+
+    #[test]
+    fn test_range_bounds() {
+        assert_eq!(ELEMENTS.range::<str, _>(..).len(), 10);
+        assert_eq!(ELEMENTS.range("boron"..="carbon").len(), 2);
+        assert_eq!(
+            ELEMENTS
+                .range::<&str, _>((Bound::Excluded("boron"), Bound::Unbounded))
+                .len(),
+            8
+        );
+        assert_eq!(ELEMENTS.range("z".."a").len(), 0);
+        assert_eq!(ELEMENTS.prefix("").len(), 10);
+    }
+
+    #[test]
+    fn test_sorted() {
+        let keys = ELEMENTS.keys_sorted();
+        assert!(keys.clone().zip(keys.skip(1)).all(|(a, b)| a < b));
+    }
+}
@@ -0,0 +1,315 @@
+//! An immutable set constructed at compile time with perfect hashing, which
+//! also supports ordered queries on its elements.
+
+use core::borrow::Borrow;
+use core::hash::Hash;
+use core::ops::{Bound, RangeBounds};
+
+use crate::PhfSet;
+
+/// An immutable set constructed at compile time with perfect hashing, which
+/// also supports ordered queries on its elements.
+///
+/// Besides the usual table of a [`PhfSet`], a `SortedPhfSet` stores the
+/// positions of its elements in sorted order, at the cost of four bytes per
+/// element. Membership tests still take constant time, while [`range`],
+/// [`first`], [`last`] and [`prefix`] use binary search over the sorted
+/// positions.
+///
+/// [`range`]: SortedPhfSet::range
+/// [`first`]: SortedPhfSet::first
+/// [`last`]: SortedPhfSet::last
+/// [`prefix`]: SortedPhfSet::prefix
+#[derive(Debug)]
+pub struct SortedPhfSet<K: 'static> {
+    set: PhfSet<K>,
+    sorted: &'static [u32],
+}
+
+impl<K> SortedPhfSet<K> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the set—users should never directly write calls to it.
+    pub const fn new(
+        seed: u64,
+        pilots_table: &'static [u16],
+        elements: &'static [K],
+        free: &'static [u32],
+        sorted: &'static [u32],
+    ) -> SortedPhfSet<K> {
+        SortedPhfSet {
+            set: PhfSet::new(seed, pilots_table, elements, free),
+            sorted,
+        }
+    }
+
+    /// Returns the underlying [`PhfSet`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let set = RAINBOW.as_set();
+    /// assert!(set.contains(&"indigo"));
+    /// ```
+    pub const fn as_set(&self) -> &PhfSet<K> {
+        &self.set
+    }
+
+    /// Returns the number of elements in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(RAINBOW.len(), 7);
+    /// ```
+    pub const fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    /// Returns `true` if the set does not contain any elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!RAINBOW.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    /// Returns the smallest element of the set, if it is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(RAINBOW.first(), Some(&"blue"));
+    /// ```
+    pub fn first(&self) -> Option<&K> {
+        self.iter_sorted().next()
+    }
+
+    /// Returns the largest element of the set, if it is not empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(RAINBOW.last(), Some(&"yellow"));
+    /// ```
+    pub fn last(&self) -> Option<&K> {
+        self.iter_sorted().next_back()
+    }
+
+    /// Returns an iterator over the elements of the set in no particular
+    /// order, which is faster than [`iter_sorted`](SortedPhfSet::iter_sorted).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(RAINBOW.iter().count(), 7);
+    /// ```
+    pub fn iter(&self) -> crate::set::Iter<'_, K> {
+        self.set.iter()
+    }
+
+    /// Returns an iterator over the elements of the set in increasing order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let colors = RAINBOW.iter_sorted().copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(
+    ///     &colors,
+    ///     &["blue", "green", "indigo", "orange", "red", "violet", "yellow"]
+    /// );
+    /// ```
+    pub fn iter_sorted(&self) -> Iter<'_, K> {
+        self.iter_positions(0, self.sorted.len())
+    }
+
+    /// Returns an iterator, in increasing order, over the elements of the set
+    /// which lie within the given range. An empty iterator is returned if the
+    /// start of the range is greater than its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let colors = RAINBOW.range("g".."p").copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&colors, &["green", "indigo", "orange"]);
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.partition_point(|element| element < start),
+            Bound::Excluded(start) => self.partition_point(|element| element <= start),
+            Bound::Unbounded => 0,
+        };
+
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.partition_point(|element| element <= end),
+            Bound::Excluded(end) => self.partition_point(|element| element < end),
+            Bound::Unbounded => self.sorted.len(),
+        };
+
+        self.iter_positions(start, usize::max(start, end))
+    }
+
+    /// Returns the number of sorted positions whose elements satisfy `pred`,
+    /// which has to hold for a prefix of the sorted elements.
+    fn partition_point<Q, P>(&self, mut pred: P) -> usize
+    where
+        K: Borrow<Q>,
+        Q: ?Sized,
+        P: FnMut(&Q) -> bool,
+    {
+        let elements = self.set.as_slice();
+        self.sorted
+            .partition_point(|&pos| pred(elements[pos as usize].borrow()))
+    }
+
+    fn iter_positions(&self, start: usize, end: usize) -> Iter<'_, K> {
+        Iter {
+            elements: self.set.as_slice(),
+            positions: self.sorted[start..end].iter(),
+        }
+    }
+}
+
+impl<K: Eq + Hash> SortedPhfSet<K> {
+    /// Returns `true` if the set contains the given element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(RAINBOW.contains(&"red"));
+    /// assert!(!RAINBOW.contains(&"pink"));
+    /// ```
+    pub fn contains(&self, element: &K) -> bool {
+        self.set.contains(element)
+    }
+
+    /// Returns a reference to the copy of the element stored in the set, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(RAINBOW.get(&"green"), Some(&"green"));
+    /// assert_eq!(RAINBOW.get(&"brown"), None);
+    /// ```
+    pub fn get(&self, element: &K) -> Option<&K> {
+        self.set.get(element)
+    }
+}
+
+impl SortedPhfSet<&'static str> {
+    /// Returns an iterator, in increasing order, over the elements of the set
+    /// which start with the given prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let colors = RAINBOW.prefix("in").copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&colors, &["indigo"]);
+    /// ```
+    pub fn prefix(&self, prefix: &str) -> Iter<'_, &'static str> {
+        let start = self.partition_point(|element: &str| element < prefix);
+        let len = self.sorted[start..]
+            .partition_point(|&pos| self.set.as_slice()[pos as usize].starts_with(prefix));
+
+        self.iter_positions(start, start + len)
+    }
+}
+
+impl<'a, K> IntoIterator for &'a SortedPhfSet<K> {
+    type Item = &'a K;
+    type IntoIter = Iter<'a, K>;
+
+    /// Iterates over the elements of the set in increasing order.
+    fn into_iter(self) -> Iter<'a, K> {
+        self.iter_sorted()
+    }
+}
+
+impl<K: Eq + Hash> PartialEq for SortedPhfSet<K> {
+    fn eq(&self, other: &Self) -> bool {
+        self.set == other.set
+    }
+}
+
+impl<K: Eq + Hash> Eq for SortedPhfSet<K> {}
+
+#[derive(Clone)]
+/// An iterator over the elements of a `SortedPhfSet` in increasing order.
+pub struct Iter<'a, K: 'a> {
+    elements: &'a [K],
+    positions: core::slice::Iter<'a, u32>,
+}
+
+impl<'a, K> Iterator for Iter<'a, K> {
+    type Item = &'a K;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.positions
+            .next()
+            .map(|&pos| &self.elements[pos as usize])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.positions.size_hint()
+    }
+}
+
+impl<'a, K> DoubleEndedIterator for Iter<'a, K> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.positions
+            .next_back()
+            .map(|&pos| &self.elements[pos as usize])
+    }
+}
+
+impl<'a, K> ExactSizeIterator for Iter<'a, K> {}
+
+impl<'a, K> core::iter::FusedIterator for Iter<'a, K> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::RAINBOW;
+
+    #[test]
+    fn test_range_bounds() {
+        assert_eq!(RAINBOW.range::<str, _>(..).len(), 7);
+        assert_eq!(RAINBOW.range("blue"..="green").len(), 2);
+        assert_eq!(RAINBOW.range("yellow"..).len(), 1);
+        assert_eq!(RAINBOW.range("z".."a").len(), 0);
+        assert_eq!(RAINBOW.prefix("x").len(), 0);
+    }
+}
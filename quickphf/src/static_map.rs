@@ -0,0 +1,398 @@
+//! An immutable map constructed at compile time, whose representation is
+//! chosen to suit its keys.
+
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::hash::Hash;
+
+use crate::map::{Iter, Keys, Values};
+use crate::PhfMap;
+
+/// The ways in which the entries of a [`StaticMap`] can be stored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Representation {
+    /// The keys are a contiguous range of integers, and each entry is stored
+    /// at the position of its key within that range.
+    Direct,
+    /// The entries are stored in a slice which is searched linearly, which is
+    /// the fastest way to search a handful of keys.
+    Linear,
+    /// The entries are stored in a slice sorted by key, which is searched by
+    /// binary search and iterated over in the order of the keys.
+    ///
+    /// It is never chosen automatically, only when requested explicitly.
+    Sorted,
+    /// The entries are stored in a [`PhfMap`].
+    Phf,
+}
+
+/// A type which can be used as a key of a [`StaticMap`].
+///
+/// Integer types, `bool` and `char` map their values to integers, so that
+/// maps whose keys form a contiguous range can be looked up by direct
+/// indexing. Other types only need an empty implementation.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf::static_map::StaticKey;
+///
+/// #[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+/// struct Isbn(&'static str);
+///
+/// impl StaticKey for Isbn {}
+/// ```
+pub trait StaticKey: Hash + Ord {
+    /// Returns the integer which the key is stored at in a map using the
+    /// [`Direct`](Representation::Direct) representation, if any.
+    ///
+    /// Distinct keys must be mapped to distinct integers.
+    fn to_index(&self) -> Option<u64> {
+        None
+    }
+}
+
+impl StaticKey for bool {
+    fn to_index(&self) -> Option<u64> {
+        Some(u64::from(*self))
+    }
+}
+
+impl StaticKey for char {
+    fn to_index(&self) -> Option<u64> {
+        Some(u64::from(u32::from(*self)))
+    }
+}
+
+macro_rules! impl_static_key_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl StaticKey for $t {
+                fn to_index(&self) -> Option<u64> {
+                    u64::try_from(*self).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_static_key_unsigned!(u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_static_key_signed {
+    ($($t:ty),*) => {
+        $(
+            impl StaticKey for $t {
+                // Negative integers wrap around, so that ranges containing
+                // zero remain contiguous modulo 2^64.
+                #[allow(clippy::cast_sign_loss)]
+                fn to_index(&self) -> Option<u64> {
+                    i64::try_from(*self).ok().map(|x| x as u64)
+                }
+            }
+        )*
+    };
+}
+
+impl_static_key_signed!(i8, i16, i32, i64, i128, isize);
+
+impl StaticKey for str {}
+
+impl StaticKey for [u8] {}
+
+impl<T: StaticKey, const N: usize> StaticKey for [T; N] {}
+
+impl<T: StaticKey + ?Sized> StaticKey for &T {
+    fn to_index(&self) -> Option<u64> {
+        (**self).to_index()
+    }
+}
+
+/// An immutable map constructed at compile time, whose representation is
+/// chosen to suit its keys.
+///
+/// `quickphf_codegen` inspects the keys of the map and stores them by direct
+/// indexing if they form a contiguous range of integers, in a slice searched
+/// linearly if there are only a few of them, and in a [`PhfMap`] otherwise.
+/// A particular [`Representation`] can also be requested explicitly, which is
+/// the only way to get a [`Sorted`](Representation::Sorted) map.
+///
+/// Regardless of its representation, a `StaticMap` has the same interface as
+/// a `PhfMap`, except that its lookups take keys which `K` can be borrowed as,
+/// rather than any [`Equivalent`](crate::Equivalent) type, since the direct
+/// and sorted representations index or order the queried key itself.
+#[derive(Debug)]
+pub struct StaticMap<K: 'static, V: 'static> {
+    repr: Repr<K, V>,
+}
+
+#[derive(Debug)]
+enum Repr<K: 'static, V: 'static> {
+    Direct {
+        start: u64,
+        entries: &'static [(K, V)],
+    },
+    Linear(&'static [(K, V)]),
+    Sorted(&'static [(K, V)]),
+    Phf(PhfMap<K, V>),
+}
+
+impl<K, V> StaticMap<K, V> {
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn direct(start: u64, entries: &'static [(K, V)]) -> StaticMap<K, V> {
+        StaticMap {
+            repr: Repr::Direct { start, entries },
+        }
+    }
+
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn linear(entries: &'static [(K, V)]) -> StaticMap<K, V> {
+        StaticMap {
+            repr: Repr::Linear(entries),
+        }
+    }
+
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn sorted(entries: &'static [(K, V)]) -> StaticMap<K, V> {
+        StaticMap {
+            repr: Repr::Sorted(entries),
+        }
+    }
+
+    #[doc(hidden)]
+    /// This function is public because it is used by `quickphf_codegen` to
+    /// instantiate the map—users should never directly write calls to it.
+    pub const fn phf(map: PhfMap<K, V>) -> StaticMap<K, V> {
+        StaticMap {
+            repr: Repr::Phf(map),
+        }
+    }
+
+    /// Returns the representation in which the entries of the map are stored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    /// use quickphf::static_map::Representation;
+    ///
+    /// assert_eq!(DIGIT_NAMES.representation(), Representation::Direct);
+    /// assert_eq!(COMPASS_BEARINGS.representation(), Representation::Linear);
+    /// assert_eq!(GREEK_LETTERS.representation(), Representation::Phf);
+    /// assert_eq!(SORTED_GREEK_LETTERS.representation(), Representation::Sorted);
+    /// ```
+    pub const fn representation(&self) -> Representation {
+        match self.repr {
+            Repr::Direct { .. } => Representation::Direct,
+            Repr::Linear(_) => Representation::Linear,
+            Repr::Sorted(_) => Representation::Sorted,
+            Repr::Phf(_) => Representation::Phf,
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(DIGIT_NAMES.len(), 10);
+    /// assert_eq!(GREEK_LETTERS.len(), 24);
+    /// ```
+    pub const fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Direct { entries, .. } | Repr::Linear(entries) | Repr::Sorted(entries) => {
+                entries.len()
+            }
+            Repr::Phf(map) => map.len(),
+        }
+    }
+
+    /// Returns `true` if the map does not contain any entries.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(!COMPASS_BEARINGS.is_empty());
+    /// assert!(EMPTY_STATIC_MAP.is_empty());
+    /// ```
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// An iterator visiting all key-value pairs, in increasing order of keys
+    /// for the `Direct` and `Sorted` representations, in the order in which
+    /// they were supplied to `quickphf_codegen` for the `Linear`
+    /// representation, and in arbitrary order otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let names = DIGIT_NAMES.iter().map(|e| e.1).collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&names[..3], &["zero", "one", "two"]);
+    /// ```
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        match &self.repr {
+            Repr::Direct { entries, .. } | Repr::Linear(entries) | Repr::Sorted(entries) => {
+                Iter::new(entries)
+            }
+            Repr::Phf(map) => map.iter(),
+        }
+    }
+
+    /// An iterator visiting all keys, in the same order as [`iter`](StaticMap::iter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let letters = SORTED_GREEK_LETTERS.keys().take(3).copied().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(&letters, &["alpha", "beta", "chi"]);
+    /// ```
+    pub fn keys(&self) -> Keys<'_, K, V> {
+        Keys { iter: self.iter() }
+    }
+
+    /// An iterator visiting all values, in the same order as [`iter`](StaticMap::iter).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// let sum = COMPASS_BEARINGS.values().sum::<u16>();
+    ///
+    /// assert_eq!(sum, 540);
+    /// ```
+    pub fn values(&self) -> Values<'_, K, V> {
+        Values { iter: self.iter() }
+    }
+
+    /// Returns the key stored in the map and a reference to its value, if
+    /// present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(DIGIT_NAMES.get_key_value(&7), Some((&7, &"seven")));
+    /// assert_eq!(GREEK_LETTERS.get_key_value("mu"), Some((&"mu", &12)));
+    /// assert_eq!(GREEK_LETTERS.get_key_value("digamma"), None);
+    /// ```
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: StaticKey + ?Sized,
+    {
+        let entry = match &self.repr {
+            Repr::Direct { start, entries } => {
+                let offset = key.to_index()?.wrapping_sub(*start);
+                entries.get(usize::try_from(offset).ok()?)?
+            }
+            Repr::Linear(entries) => entries.iter().find(|e| e.0.borrow() == key)?,
+            Repr::Sorted(entries) => {
+                let pos = entries.binary_search_by(|e| e.0.borrow().cmp(key)).ok()?;
+                &entries[pos]
+            }
+            Repr::Phf(map) => return map.get_key_value(key),
+        };
+
+        if entry.0.borrow() == key {
+            Some((&entry.0, &entry.1))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the value corresponding to the key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert_eq!(DIGIT_NAMES.get(&0), Some(&"zero"));
+    /// assert_eq!(DIGIT_NAMES.get(&10), None);
+    /// assert_eq!(COMPASS_BEARINGS.get("W"), Some(&270));
+    /// assert_eq!(SORTED_GREEK_LETTERS.get("omega"), Some(&24));
+    /// ```
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: StaticKey + ?Sized,
+    {
+        self.get_key_value(key).map(|e| e.1)
+    }
+
+    /// Returns `true` if the map contains the given key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf::examples::*;
+    ///
+    /// assert!(COMPASS_BEARINGS.contains_key("N"));
+    /// assert!(!COMPASS_BEARINGS.contains_key("NE"));
+    /// ```
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: StaticKey + ?Sized,
+    {
+        self.get_key_value(key).is_some()
+    }
+}
+
+impl<'a, K, V> IntoIterator for &'a StaticMap<K, V> {
+    type Item = &'a (K, V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StaticKey;
+    use crate::examples::{
+        COMPASS_BEARINGS, DIGIT_NAMES, EMPTY_STATIC_MAP, GREEK_LETTERS, SORTED_GREEK_LETTERS,
+    };
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(EMPTY_STATIC_MAP.get("Burrich"), None);
+        assert!(EMPTY_STATIC_MAP.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_representations_agree() {
+        for (key, value) in &GREEK_LETTERS {
+            assert_eq!(SORTED_GREEK_LETTERS.get(key), Some(value));
+        }
+        for (key, value) in &COMPASS_BEARINGS {
+            assert_eq!(COMPASS_BEARINGS.get(key), Some(value));
+        }
+    }
+
+    #[test]
+    fn test_direct_out_of_range() {
+        assert_eq!(DIGIT_NAMES.get(&u8::MAX), None);
+        assert_eq!((-1i8).to_index(), Some(u64::MAX));
+        assert_eq!(u128::MAX.to_index(), None);
+    }
+}
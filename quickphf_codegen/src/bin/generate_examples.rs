@@ -3,20 +3,147 @@
 use std::io::Write as IOWrite;
 use std::{fmt::Write, fs::File, io::BufWriter, path::Path};
 
-use quickphf_codegen::{build_map, build_raw_map, build_set, CodeWriter};
+use quickphf_codegen::{
+    build_alias_map, build_arena_map, build_arena_span_map, build_bimap, build_blob_map,
+    build_columns, build_counter_map, build_dedup_map, build_interner, build_keyword_map,
+    build_lookup_fn, build_map, build_map_array, build_multimap, build_normalized_map,
+    build_ordered_map, build_packed_raw_map, build_raw_map, build_records, build_set,
+    build_soa_map, build_sorted_map, build_sorted_set, build_static_map, build_static_map_as,
+    AliasWriter, AsciiCaseInsensitive, BiMapWriter, CodeWriter, DebugInstantiable, DedupWriter,
+    MapArrayWriter, MultiMapWriter, PackedValue, Representation, SortedWriter, Trim,
+    UnicodeLowercase,
+};
 
 const DESTINATION: &str = "examples.rs";
 
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+const COUNTRY_DEFINITIONS: &str = "#[derive(Debug)]
+pub struct Country {
+    pub iso2: &'static str,
+    pub iso3: &'static str,
+    pub name: &'static str,
+}
+
+pub struct Iso2;
+
+impl crate::records::RecordKey<Country> for Iso2 {
+    type Key = str;
+    const INDEX: usize = ISO2;
+
+    fn key(record: &Country) -> &str {
+        record.iso2
+    }
+}
+
+pub struct Iso3;
+
+impl crate::records::RecordKey<Country> for Iso3 {
+    type Key = str;
+    const INDEX: usize = ISO3;
+
+    fn key(record: &Country) -> &str {
+        record.iso3
+    }
+}
+";
+
+// The fields are only read through the `Debug` representation.
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Country {
+    iso2: &'static str,
+    iso3: &'static str,
+    name: &'static str,
+}
+
+impl DebugInstantiable for Country {}
+
+const STATE_DEFINITIONS: &str = "#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    Solid,
+    Liquid,
+    Gas,
+}
+
+impl crate::packed::PackedValue for State {
+    fn to_bits(self) -> u32 {
+        self as u32
+    }
+
+    fn from_bits(bits: u32) -> State {
+        match bits {
+            0 => State::Solid,
+            1 => State::Liquid,
+            _ => State::Gas,
+        }
+    }
+}
+";
+
+#[derive(Clone, Copy)]
+enum State {
+    Solid,
+    Liquid,
+    Gas,
+}
+
+impl PackedValue for State {
+    fn to_bits(self) -> u32 {
+        self as u32
+    }
+
+    fn from_bits(bits: u32) -> State {
+        match bits {
+            0 => State::Solid,
+            1 => State::Liquid,
+            _ => State::Gas,
+        }
+    }
+}
+
 fn main() {
     let mut buffer = String::new();
 
-    let months = [
-        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
-    ];
+    write_raw_maps(&mut buffer);
+    write_lookup_fns(&mut buffer);
+    write_packed_raw_maps(&mut buffer);
+    write_maps(&mut buffer);
+    write_map_arrays(&mut buffer);
+    write_bimaps(&mut buffer);
+    write_multimaps(&mut buffer);
+    write_dedup_maps(&mut buffer);
+    write_alias_maps(&mut buffer);
+    write_soa_maps(&mut buffer);
+    write_blob_maps(&mut buffer);
+    write_static_maps(&mut buffer);
+    write_keyword_maps(&mut buffer);
+    write_normalized_maps(&mut buffer);
+    write_arena_maps(&mut buffer);
+    write_ordered_maps(&mut buffer);
+    write_interners(&mut buffer);
+    write_records(&mut buffer);
+    write_sorted_maps(&mut buffer);
+    write_counter_maps(&mut buffer);
+    write_sets(&mut buffer);
+    write_sorted_sets(&mut buffer);
+    write_columns(&mut buffer);
+
+    let buffer = buffer.replace("::quickphf::", "crate::");
+
+    let path = Path::new(DESTINATION);
+    let mut file = BufWriter::new(File::create(path).unwrap());
+    write!(&mut file, "{}", buffer).unwrap();
+}
+
+/// Writes the example `RawPhfMap`s.
+fn write_raw_maps(buffer: &mut String) {
     let holidays = [2, 1, 0, 0, 0, 1, 1, 0, 1, 1, 2, 1];
-    let holidays_per_month = build_raw_map(&months, &holidays);
+    let holidays_per_month = build_raw_map(&MONTHS, &holidays);
     writeln!(
-        &mut buffer,
+        buffer,
         "pub static HOLIDAYS_PER_MONTH: crate::RawPhfMap<&'static str, i32> = {};\n",
         holidays_per_month
     )
@@ -24,33 +151,595 @@ fn main() {
 
     let empty_raw_map: CodeWriter<'_, &str, i32> = build_raw_map(&[], &[]);
     writeln!(
-        &mut buffer,
+        buffer,
         "pub static EMPTY_RAW_MAP: crate::RawPhfMap<&'static str, i32> = {};\n",
         empty_raw_map
     )
     .unwrap();
+}
 
+/// Writes the example lookup functions.
+fn write_lookup_fns(buffer: &mut String) {
+    let month_numbers: Vec<u8> = (1..=12).collect();
+    writeln!(
+        buffer,
+        "{}\n",
+        build_lookup_fn("month_number", &MONTHS, &month_numbers)
+    )
+    .unwrap();
+}
+
+/// Writes the example `PackedRawPhfMap`s.
+fn write_packed_raw_maps(buffer: &mut String) {
+    let letters = [
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r",
+        "s", "t", "u", "v", "w", "x", "y", "z",
+    ];
+    let positions: Vec<u8> = (1..=26).collect();
+    let letter_positions = build_packed_raw_map(&letters, &positions);
+    writeln!(
+        buffer,
+        "pub static LETTER_POSITIONS: crate::PackedRawPhfMap<&'static str, u8> = {};\n",
+        letter_positions
+    )
+    .unwrap();
+
+    let elements = [
+        "H", "He", "Li", "Be", "B", "C", "N", "O", "F", "Ne", "Na", "Mg", "Al", "Si", "P", "S",
+        "Cl", "Ar", "K", "Ca", "Br", "Hg",
+    ];
+    let states = elements.map(|element| match element {
+        "H" | "He" | "N" | "O" | "F" | "Ne" | "Cl" | "Ar" => State::Gas,
+        "Br" | "Hg" => State::Liquid,
+        _ => State::Solid,
+    });
+    let element_states = build_packed_raw_map(&elements, &states);
+    writeln!(buffer, "{}", STATE_DEFINITIONS).unwrap();
+    writeln!(
+        buffer,
+        "pub static ELEMENT_STATES: crate::PackedRawPhfMap<&'static str, State> = {};\n",
+        element_states
+    )
+    .unwrap();
+}
+
+/// Writes the example `PhfMap`s.
+fn write_maps(buffer: &mut String) {
     let roots = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
     let fourth_powers = roots.map(|x| x * x * x * x);
     let powers_to_roots = build_map(&fourth_powers, &roots);
     writeln!(
-        &mut buffer,
+        buffer,
         "pub static FOURTH_POWERS_TO_ROOTS: crate::PhfMap<i32, i32> = {};\n",
         powers_to_roots
     )
     .unwrap();
 
+    let extensions = [
+        "css", "gif", "html", "jpg", "js", "json", "png", "svg", "txt",
+    ];
+    let mime_types = [
+        "text/css",
+        "image/gif",
+        "text/html",
+        "image/jpeg",
+        "text/javascript",
+        "application/json",
+        "image/png",
+        "image/svg+xml",
+        "text/plain",
+    ];
+    writeln!(
+        buffer,
+        "pub static CONTENT_TYPES: crate::PhfMap<&'static str, &'static str> = {};\n",
+        build_map(&extensions, &mime_types)
+    )
+    .unwrap();
+
+    let routes = [
+        ("GET", "/"),
+        ("GET", "/about"),
+        ("GET", "/login"),
+        ("POST", "/login"),
+        ("POST", "/logout"),
+    ];
+    let handlers: [u16; 5] = [0, 1, 2, 3, 4];
+    writeln!(
+        buffer,
+        "pub static ROUTES: crate::PhfMap<(&'static str, &'static str), u16> = {};\n",
+        build_map(&routes, &handlers)
+    )
+    .unwrap();
+
     let empty_map: CodeWriter<'_, &str, i32> = build_map(&[], &[]);
     writeln!(
-        &mut buffer,
+        buffer,
         "pub static EMPTY_MAP: crate::PhfMap<&'static str, i32> = {};\n",
         empty_map
     )
     .unwrap();
+}
+
+/// Writes the example `PhfMapArray`s.
+fn write_map_arrays(buffer: &mut String) {
+    let bases: [u32; 5] = [1, 2, 3, 4, 5];
+    let squares = bases.map(|x| x * x);
+    let squares_array = build_map_array(&bases, &squares);
+    let (n, b, f) = squares_array.array_lengths();
+    writeln!(
+        buffer,
+        "pub const SQUARES_ARRAY: crate::PhfMapArray<u32, u32, {}, {}, {}> = {};\n",
+        n, b, f, squares_array
+    )
+    .unwrap();
 
+    let empty_map_array: MapArrayWriter<'_, &str, i32> = build_map_array(&[], &[]);
+    let (n, b, f) = empty_map_array.array_lengths();
+    writeln!(
+        buffer,
+        "pub const EMPTY_MAP_ARRAY: crate::PhfMapArray<&'static str, i32, {}, {}, {}> = {};\n",
+        n, b, f, empty_map_array
+    )
+    .unwrap();
+}
+
+/// Writes the example `PhfBiMap`s.
+fn write_bimaps(buffer: &mut String) {
+    let codes: [u16; 5] = [200, 201, 301, 404, 500];
+    let reasons = [
+        "OK",
+        "Created",
+        "Moved Permanently",
+        "Not Found",
+        "Internal Server Error",
+    ];
+    let http_statuses = build_bimap(&codes, &reasons);
+    writeln!(
+        buffer,
+        "pub static HTTP_STATUSES: crate::PhfBiMap<u16, &'static str> = {};\n",
+        http_statuses
+    )
+    .unwrap();
+
+    let empty_bimap: BiMapWriter<'_, &str, i32> = build_bimap(&[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_BIMAP: crate::PhfBiMap<&'static str, i32> = {};\n",
+        empty_bimap
+    )
+    .unwrap();
+}
+
+/// Writes the example `PhfMultiMap`s.
+fn write_multimaps(buffer: &mut String) {
+    let extensions = ["js", "js", "png", "xml", "xml", "ico", "ico"];
+    let mime_types = [
+        "application/javascript",
+        "text/javascript",
+        "image/png",
+        "application/xml",
+        "text/xml",
+        "image/x-icon",
+        "image/vnd.microsoft.icon",
+    ];
+    let extensions_to_mime_types = build_multimap(&extensions, &mime_types);
+    writeln!(
+        buffer,
+        "pub static MIME_TYPES: crate::PhfMultiMap<&'static str, &'static str> = {};\n",
+        extensions_to_mime_types
+    )
+    .unwrap();
+
+    let empty_multimap: MultiMapWriter<'_, &str, &str> = build_multimap(&[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_MULTIMAP: crate::PhfMultiMap<&'static str, &'static str> = {};\n",
+        empty_multimap
+    )
+    .unwrap();
+}
+
+/// Writes the example `DedupPhfMap`s.
+fn write_dedup_maps(buffer: &mut String) {
+    let countries = ["DE", "FR", "IT", "ES", "NL", "US", "EC", "SV", "JP"];
+    let currencies = [
+        "EUR", "EUR", "EUR", "EUR", "EUR", "USD", "USD", "USD", "JPY",
+    ];
+    let country_currencies = build_dedup_map(&countries, &currencies);
+    writeln!(
+        buffer,
+        "pub static CURRENCIES: crate::DedupPhfMap<&'static str, &'static str> = {};\n",
+        country_currencies
+    )
+    .unwrap();
+
+    let empty_dedup_map: DedupWriter<'_, &str, &str> = build_dedup_map(&[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_DEDUP_MAP: crate::DedupPhfMap<&'static str, &'static str> = {};\n",
+        empty_dedup_map
+    )
+    .unwrap();
+}
+
+/// Writes the example `AliasPhfMap`s.
+fn write_alias_maps(buffer: &mut String) {
+    let zones = ["Europe/Kyiv", "Asia/Kolkata", "America/New_York", "Etc/UTC"];
+    let utc_offsets = [120, 330, -300, 0];
+    let zone_aliases = [
+        ("Europe/Kiev", "Europe/Kyiv"),
+        ("Asia/Calcutta", "Asia/Kolkata"),
+        ("US/Eastern", "America/New_York"),
+        ("UTC", "Etc/UTC"),
+        ("Etc/Zulu", "Etc/UTC"),
+    ];
+    let timezones = build_alias_map(&zones, &utc_offsets, &zone_aliases);
+    writeln!(
+        buffer,
+        "pub static TIMEZONES: crate::AliasPhfMap<&'static str, i32> = {};\n",
+        timezones
+    )
+    .unwrap();
+
+    let empty_alias_map: AliasWriter<'_, &str, i32> = build_alias_map(&[], &[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_ALIAS_MAP: crate::AliasPhfMap<&'static str, i32> = {};\n",
+        empty_alias_map
+    )
+    .unwrap();
+}
+
+/// Writes the example `SoaPhfMap`s.
+fn write_soa_maps(buffer: &mut String) {
+    let status_codes: [u16; 10] = [200, 201, 204, 301, 400, 403, 404, 500, 502, 503];
+    let reasons = [
+        "OK",
+        "Created",
+        "No Content",
+        "Moved Permanently",
+        "Bad Request",
+        "Forbidden",
+        "Not Found",
+        "Internal Server Error",
+        "Bad Gateway",
+        "Service Unavailable",
+    ];
+    let status_reasons = build_soa_map(&status_codes, &reasons);
+    writeln!(
+        buffer,
+        "pub static STATUS_REASONS: crate::SoaPhfMap<u16, &'static str> = {};\n",
+        status_reasons
+    )
+    .unwrap();
+
+    let empty_soa_map: CodeWriter<'_, u16, &str> = build_soa_map(&[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_SOA_MAP: crate::SoaPhfMap<u16, &'static str> = {};\n",
+        empty_soa_map
+    )
+    .unwrap();
+}
+
+/// Writes the example `BlobPhfMap`s.
+fn write_blob_maps(buffer: &mut String) {
+    // The tables of blob maps are written next to the examples module, which
+    // embeds them with paths relative to itself.
+    let squares: Vec<u32> = (0..1000).map(|x| x * x).collect();
+    let roots: Vec<u16> = (0..1000).collect();
+    let square_roots = build_blob_map(&squares, &roots);
+    std::fs::write("square_roots.bin", square_roots.blob()).unwrap();
+    writeln!(
+        buffer,
+        "pub static SQUARE_ROOTS: crate::BlobPhfMap<u32, u16> = {};\n",
+        square_roots.code("square_roots.bin")
+    )
+    .unwrap();
+
+    let empty_blob_map = build_blob_map::<u32, u16>(&[], &[]);
+    std::fs::write("empty_blob_map.bin", empty_blob_map.blob()).unwrap();
+    writeln!(
+        buffer,
+        "pub static EMPTY_BLOB_MAP: crate::BlobPhfMap<u32, u16> = {};\n",
+        empty_blob_map.code("empty_blob_map.bin")
+    )
+    .unwrap();
+}
+
+/// Writes the example `StaticMap`s.
+fn write_static_maps(buffer: &mut String) {
+    let digits: Vec<u8> = (0..10).collect();
+    let digit_names = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+    writeln!(
+        buffer,
+        "pub static DIGIT_NAMES: crate::StaticMap<u8, &'static str> = {};\n",
+        build_static_map(&digits, &digit_names)
+    )
+    .unwrap();
+
+    let compass_points = ["N", "E", "S", "W"];
+    let bearings: [u16; 4] = [0, 90, 180, 270];
+    writeln!(
+        buffer,
+        "pub static COMPASS_BEARINGS: crate::StaticMap<&'static str, u16> = {};\n",
+        build_static_map(&compass_points, &bearings)
+    )
+    .unwrap();
+
+    let greek_letters = [
+        "alpha", "beta", "gamma", "delta", "epsilon", "zeta", "eta", "theta", "iota", "kappa",
+        "lambda", "mu", "nu", "xi", "omicron", "pi", "rho", "sigma", "tau", "upsilon", "phi",
+        "chi", "psi", "omega",
+    ];
+    let positions: Vec<u8> = (1..=24).collect();
+    writeln!(
+        buffer,
+        "pub static GREEK_LETTERS: crate::StaticMap<&'static str, u8> = {};\n",
+        build_static_map(&greek_letters, &positions)
+    )
+    .unwrap();
+
+    writeln!(
+        buffer,
+        "pub static SORTED_GREEK_LETTERS: crate::StaticMap<&'static str, u8> = {};\n",
+        build_static_map_as(&greek_letters, &positions, Representation::Sorted)
+    )
+    .unwrap();
+
+    let empty_static_map = build_static_map::<&str, u8>(&[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_STATIC_MAP: crate::StaticMap<&'static str, u8> = {};\n",
+        empty_static_map
+    )
+    .unwrap();
+}
+
+/// Writes the example `KeywordPhfMap`s.
+fn write_keyword_maps(buffer: &mut String) {
+    let c_keywords = [
+        "auto", "break", "case", "char", "const", "continue", "default", "do", "double", "else",
+        "enum", "extern", "float", "for", "goto", "if", "int", "long", "register", "return",
+        "short", "signed", "sizeof", "static", "struct", "switch", "typedef", "union", "unsigned",
+        "void", "volatile", "while",
+    ];
+    let tokens: Vec<u8> = (1..=32).collect();
+    writeln!(
+        buffer,
+        "pub static C_KEYWORDS: crate::KeywordPhfMap<str, u8> = {};\n",
+        build_keyword_map(&c_keywords, &tokens)
+    )
+    .unwrap();
+
+    let http_methods: [&[u8]; 9] = [
+        b"GET", b"HEAD", b"POST", b"PUT", b"DELETE", b"CONNECT", b"OPTIONS", b"TRACE", b"PATCH",
+    ];
+    let method_ids: Vec<u8> = (1..=9).collect();
+    writeln!(
+        buffer,
+        "pub static HTTP_METHODS: crate::KeywordPhfMap<[u8], u8> = {};\n",
+        build_keyword_map(&http_methods, &method_ids)
+    )
+    .unwrap();
+
+    let empty_keyword_map = build_keyword_map::<str, u8>(&[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_KEYWORD_MAP: crate::KeywordPhfMap<str, u8> = {};\n",
+        empty_keyword_map
+    )
+    .unwrap();
+}
+
+/// Writes the example `NormalizedPhfMap`s.
+fn write_normalized_maps(buffer: &mut String) {
+    let header_names = [
+        "Accept",
+        "Content-Length",
+        "Content-Type",
+        "Host",
+        "Referer",
+        "User-Agent",
+        "Cookie",
+        "X-A-Very-Long-Header-Name-Which-Does-Not-Fit-In-Sixty-Four-Bytes",
+    ];
+    let header_ids: Vec<u8> = (0..8).collect();
+    writeln!(
+        buffer,
+        "pub static HEADER_IDS: crate::NormalizedPhfMap<u8, crate::normalized_map::AsciiCaseInsensitive> = {};\n",
+        build_normalized_map(&header_names, &header_ids, AsciiCaseInsensitive)
+    )
+    .unwrap();
+
+    let setting_names = ["Größe", "Strasse", "Verbose", "Ελληνικά"];
+    let setting_defaults = [true, false, true, false];
+    writeln!(
+        buffer,
+        "pub static SETTINGS: crate::NormalizedPhfMap<bool, crate::normalized_map::Trim<crate::normalized_map::UnicodeLowercase>> = {};\n",
+        build_normalized_map(&setting_names, &setting_defaults, Trim(UnicodeLowercase))
+    )
+    .unwrap();
+
+    let empty_normalized_map = build_normalized_map::<_, u8>(&[], &[], AsciiCaseInsensitive);
+    writeln!(
+        buffer,
+        "pub static EMPTY_NORMALIZED_MAP: crate::NormalizedPhfMap<u8, crate::normalized_map::AsciiCaseInsensitive> = {};\n",
+        empty_normalized_map
+    )
+    .unwrap();
+}
+
+/// Writes the example `ArenaPhfMap`s.
+fn write_arena_maps(buffer: &mut String) {
+    let countries = [
+        "Argentina",
+        "Bolivia",
+        "Brazil",
+        "Chile",
+        "Colombia",
+        "Peru",
+    ];
+    let capitals = [
+        "Buenos Aires",
+        "Sucre",
+        "Brasília",
+        "Santiago",
+        "Bogotá",
+        "Lima",
+    ];
+    let country_capitals = build_arena_span_map(&countries, &capitals);
+    writeln!(
+        buffer,
+        "pub static CAPITALS: crate::ArenaPhfMap<str, crate::arena::Span> = {};\n",
+        country_capitals
+    )
+    .unwrap();
+
+    let signatures: [&[u8]; 5] = [b"%PDF", b"\x89PNG", b"GIF8", b"PK\x03\x04", b"\x7fELF"];
+    let signature_types = [
+        "application/pdf",
+        "image/png",
+        "image/gif",
+        "application/zip",
+        "application/x-elf",
+    ];
+    let file_signatures = build_arena_map(&signatures, &signature_types);
+    writeln!(
+        buffer,
+        "pub static FILE_SIGNATURES: crate::ArenaPhfMap<[u8], &'static str> = {};\n",
+        file_signatures
+    )
+    .unwrap();
+
+    let empty_arena_map = build_arena_map::<str, u32>(&[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_ARENA_MAP: crate::ArenaPhfMap<str, u32> = {};\n",
+        empty_arena_map
+    )
+    .unwrap();
+}
+
+/// Writes the example `OrderedPhfMap`s.
+fn write_ordered_maps(buffer: &mut String) {
+    let planets = [
+        "mercury", "venus", "earth", "mars", "jupiter", "saturn", "uranus", "neptune",
+    ];
+    let moons = [0, 0, 1, 2, 95, 146, 28, 16];
+    let planet_moons = build_ordered_map(&planets, &moons);
+    writeln!(
+        buffer,
+        "pub static PLANET_MOONS: crate::OrderedPhfMap<&'static str, u32> = {};\n",
+        planet_moons
+    )
+    .unwrap();
+
+    let empty_ordered_map: CodeWriter<'_, &str, u32> = build_ordered_map(&[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_ORDERED_MAP: crate::OrderedPhfMap<&'static str, u32> = {};\n",
+        empty_ordered_map
+    )
+    .unwrap();
+}
+
+/// Writes the example `PhfInterner`s.
+fn write_interners(buffer: &mut String) {
+    let keywords = [
+        "as", "break", "const", "continue", "crate", "else", "enum", "fn", "for", "if",
+    ];
+    let keyword_interner = build_interner(&keywords);
+    writeln!(
+        buffer,
+        "pub static KEYWORDS: crate::PhfInterner = {};\n",
+        keyword_interner
+    )
+    .unwrap();
+
+    let empty_interner = build_interner(&[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_INTERNER: crate::PhfInterner = {};\n",
+        empty_interner
+    )
+    .unwrap();
+}
+
+/// Writes the example `PhfRecords`s.
+fn write_records(buffer: &mut String) {
+    let countries = [
+        ("DE", "DEU", "Germany"),
+        ("FR", "FRA", "France"),
+        ("JP", "JPN", "Japan"),
+        ("BR", "BRA", "Brazil"),
+        ("CA", "CAN", "Canada"),
+    ]
+    .map(|(iso2, iso3, name)| Country { iso2, iso3, name });
+    let country_records = build_records(&countries)
+        .index("ISO2", |country| country.iso2)
+        .index("ISO3", |country| country.iso3);
+    writeln!(buffer, "{}", COUNTRY_DEFINITIONS).unwrap();
+    writeln!(buffer, "{}", country_records.index_constants()).unwrap();
+    writeln!(
+        buffer,
+        "pub static COUNTRIES: crate::PhfRecords<Country> = {};\n",
+        country_records
+    )
+    .unwrap();
+}
+
+/// Writes the example `SortedPhfMap`s.
+fn write_sorted_maps(buffer: &mut String) {
+    let elements = [
+        "hydrogen",
+        "helium",
+        "lithium",
+        "beryllium",
+        "boron",
+        "carbon",
+        "nitrogen",
+        "oxygen",
+        "fluorine",
+        "neon",
+    ];
+    let atomic_numbers: [u32; 10] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    let elements_map = build_sorted_map(&elements, &atomic_numbers);
+    writeln!(
+        buffer,
+        "pub static ELEMENTS: crate::SortedPhfMap<&'static str, u32> = {};\n",
+        elements_map
+    )
+    .unwrap();
+
+    let empty_sorted_map: SortedWriter<'_, &str, u32> = build_sorted_map(&[], &[]);
+    writeln!(
+        buffer,
+        "pub static EMPTY_SORTED_MAP: crate::SortedPhfMap<&'static str, u32> = {};\n",
+        empty_sorted_map
+    )
+    .unwrap();
+}
+
+/// Writes the example maps of atomic counters.
+fn write_counter_maps(buffer: &mut String) {
+    let fruit = ["apple", "banana", "cherry", "grape"];
+    let fruit_counters = build_counter_map(&fruit);
+    writeln!(
+        buffer,
+        "pub static FRUIT_COUNTERS: crate::PhfMap<&'static str, ::core::sync::atomic::AtomicU64> = {};\n",
+        fruit_counters
+    )
+    .unwrap();
+}
+
+/// Writes the example `PhfSet`s.
+fn write_sets(buffer: &mut String) {
     let digits_set = build_set(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
     writeln!(
-        &mut buffer,
+        buffer,
         "pub static DIGITS: crate::PhfSet<i32> = {};\n",
         digits_set
     )
@@ -58,7 +747,7 @@ fn main() {
 
     let even_digits_set = build_set(&[0, 2, 4, 6, 8]);
     writeln!(
-        &mut buffer,
+        buffer,
         "pub static EVEN_DIGITS: crate::PhfSet<i32> = {};\n",
         even_digits_set
     )
@@ -66,23 +755,91 @@ fn main() {
 
     let prime_digits_set = build_set(&[2, 3, 5, 7]);
     writeln!(
-        &mut buffer,
+        buffer,
         "pub static PRIME_DIGITS: crate::PhfSet<i32> = {};\n",
         prime_digits_set
     )
     .unwrap();
 
+    let hop_by_hop_headers = build_set(&[
+        "connection",
+        "keep-alive",
+        "proxy-authenticate",
+        "proxy-authorization",
+        "te",
+        "trailer",
+        "transfer-encoding",
+        "upgrade",
+    ]);
+    writeln!(
+        buffer,
+        "pub static HOP_BY_HOP_HEADERS: crate::PhfSet<&'static str> = {};\n",
+        hop_by_hop_headers
+    )
+    .unwrap();
+
     let empty_set: CodeWriter<'_, u64> = build_set(&[]);
     writeln!(
-        &mut buffer,
+        buffer,
         "pub static EMPTY_SET: crate::PhfSet<u64> = {};\n",
         empty_set
     )
     .unwrap();
+}
 
-    let buffer = buffer.replace("::quickphf::", "crate::");
+/// Writes the example `SortedPhfSet`s.
+fn write_sorted_sets(buffer: &mut String) {
+    let rainbow = build_sorted_set(&[
+        "red", "orange", "yellow", "green", "blue", "indigo", "violet",
+    ]);
+    writeln!(
+        buffer,
+        "pub static RAINBOW: crate::SortedPhfSet<&'static str> = {};\n",
+        rainbow
+    )
+    .unwrap();
+}
 
-    let path = Path::new(DESTINATION);
-    let mut file = BufWriter::new(File::create(path).unwrap());
-    write!(&mut file, "{}", buffer).unwrap();
+/// Writes the example `PhfColumns`s.
+fn write_columns(buffer: &mut String) {
+    let units = ["mm", "cm", "m", "km", "mg", "g", "kg", "t"];
+    let unit_factors: [u64; 8] = [1, 10, 1000, 1_000_000, 1, 1000, 1_000_000, 1_000_000_000];
+    let unit_dimensions = [
+        "length", "length", "length", "length", "mass", "mass", "mass", "mass",
+    ];
+    let unit_names = [
+        "millimetre",
+        "centimetre",
+        "metre",
+        "kilometre",
+        "milligram",
+        "gram",
+        "kilogram",
+        "tonne",
+    ];
+    let unit_columns = build_columns(&units);
+    writeln!(
+        buffer,
+        "pub static UNITS: crate::PhfColumns<&'static str> = {};\n",
+        unit_columns
+    )
+    .unwrap();
+    writeln!(
+        buffer,
+        "pub static UNIT_FACTORS: &[u64] = {};\n",
+        unit_columns.column(&unit_factors)
+    )
+    .unwrap();
+    writeln!(
+        buffer,
+        "pub static UNIT_DIMENSIONS: &[&str] = {};\n",
+        unit_columns.column(&unit_dimensions)
+    )
+    .unwrap();
+    writeln!(
+        buffer,
+        "pub static UNIT_NAMES: &[&str] = {};\n",
+        unit_columns.column(&unit_names)
+    )
+    .unwrap();
 }
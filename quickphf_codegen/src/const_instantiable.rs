@@ -1,45 +1,156 @@
 use core::fmt;
+use core::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicU16, AtomicU32,
+    AtomicU64, AtomicU8, AtomicUsize, Ordering,
+};
 
 /// Provides a way to generate code which instantiates values of the
 /// implementing type in a `const` context.
 pub trait ConstInstantiable {
+    /// Whether values of this type have interior mutability.
+    ///
+    /// Such values cannot be placed behind a reference in a `const`
+    /// expression, so tables containing them are stored in a named `static`
+    /// instead, whose type is printed using [`fmt_type`](Self::fmt_type).
+    const INTERIOR_MUTABLE: bool = false;
+
     /// Print a `const` expression that can be used to instantiate this value.
     #[allow(clippy::missing_errors_doc)]
     fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Print the name of this type, as it should appear in a `static` item.
+    ///
+    /// This is used for tables containing values with interior mutability,
+    /// and for the tables of the function generated by
+    /// [`build_lookup_fn`](crate::build_lookup_fn).
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics with a message naming the type.
+    #[allow(clippy::missing_errors_doc)]
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result
+    where
+        Self: Sized,
+    {
+        let _ = f;
+        missing_fmt_type::<Self>()
+    }
+
+    /// Print the name of this type with its lifetimes written out as
+    /// `'static`, as it should appear in the signature of the function
+    /// generated by [`build_lookup_fn`](crate::build_lookup_fn).
+    ///
+    /// The default implementation defers to [`fmt_type`](Self::fmt_type),
+    /// which is correct for types without references.
+    #[allow(clippy::missing_errors_doc)]
+    fn fmt_static_type(f: &mut fmt::Formatter<'_>) -> fmt::Result
+    where
+        Self: Sized,
+    {
+        Self::fmt_type(f)
+    }
+}
+
+fn missing_fmt_type<T>() -> ! {
+    panic!(
+        "`{}` does not implement `ConstInstantiable::fmt_type`, which is needed to name it in the generated code",
+        core::any::type_name::<T>()
+    )
 }
 
 /// Provides blanket implementation of [`ConstInstantiable`] which defers to
 /// [`Debug`](core::fmt::Debug) representation of type.
-pub trait DebugInstantiable: std::fmt::Debug {}
+pub trait DebugInstantiable: std::fmt::Debug {
+    /// Print the name of this type, as it should appear in a `static` item.
+    ///
+    /// See [`ConstInstantiable::fmt_type`].
+    ///
+    /// # Panics
+    ///
+    /// The default implementation panics with a message naming the type.
+    #[allow(clippy::missing_errors_doc)]
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result
+    where
+        Self: Sized,
+    {
+        let _ = f;
+        missing_fmt_type::<Self>()
+    }
+
+    /// Print the name of this type with its lifetimes written out as
+    /// `'static`.
+    ///
+    /// See [`ConstInstantiable::fmt_static_type`].
+    #[allow(clippy::missing_errors_doc)]
+    fn fmt_static_type(f: &mut fmt::Formatter<'_>) -> fmt::Result
+    where
+        Self: Sized,
+    {
+        Self::fmt_type(f)
+    }
+}
 
 impl<T: DebugInstantiable> ConstInstantiable for T {
     fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
     }
+
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as DebugInstantiable>::fmt_type(f)
+    }
+
+    fn fmt_static_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        <T as DebugInstantiable>::fmt_static_type(f)
+    }
 }
 
-impl DebugInstantiable for () {}
-impl DebugInstantiable for bool {}
-impl DebugInstantiable for &str {}
-impl DebugInstantiable for char {}
-impl DebugInstantiable for i8 {}
-impl DebugInstantiable for i16 {}
-impl DebugInstantiable for i32 {}
-impl DebugInstantiable for i64 {}
-impl DebugInstantiable for i128 {}
-impl DebugInstantiable for isize {}
-impl DebugInstantiable for u8 {}
-impl DebugInstantiable for u16 {}
-impl DebugInstantiable for u32 {}
-impl DebugInstantiable for u64 {}
-impl DebugInstantiable for u128 {}
-impl DebugInstantiable for usize {}
+macro_rules! impl_debug_instantiable {
+    ($($ty:ty => $name:expr),* $(,)?) => {
+        $(
+            impl DebugInstantiable for $ty {
+                fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str($name)
+                }
+            }
+        )*
+    };
+}
+
+impl_debug_instantiable!(
+    () => "()",
+    bool => "bool",
+    char => "char",
+    i8 => "i8",
+    i16 => "i16",
+    i32 => "i32",
+    i64 => "i64",
+    i128 => "i128",
+    isize => "isize",
+    u8 => "u8",
+    u16 => "u16",
+    u32 => "u32",
+    u64 => "u64",
+    u128 => "u128",
+    usize => "usize",
+);
+
+impl DebugInstantiable for &str {
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("&str")
+    }
+
+    fn fmt_static_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("&'static str")
+    }
+}
 
 impl<T1, T2> ConstInstantiable for (T1, T2)
 where
     T1: ConstInstantiable,
     T2: ConstInstantiable,
 {
+    const INTERIOR_MUTABLE: bool = T1::INTERIOR_MUTABLE || T2::INTERIOR_MUTABLE;
+
     fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "(")?;
         self.0.fmt_const_new(f)?;
@@ -47,6 +158,22 @@ where
         self.1.fmt_const_new(f)?;
         write!(f, ")")
     }
+
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        T1::fmt_type(f)?;
+        write!(f, ", ")?;
+        T2::fmt_type(f)?;
+        write!(f, ")")
+    }
+
+    fn fmt_static_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        T1::fmt_static_type(f)?;
+        write!(f, ", ")?;
+        T2::fmt_static_type(f)?;
+        write!(f, ")")
+    }
 }
 
 impl<T1, T2, T3> ConstInstantiable for (T1, T2, T3)
@@ -55,6 +182,9 @@ where
     T2: ConstInstantiable,
     T3: ConstInstantiable,
 {
+    const INTERIOR_MUTABLE: bool =
+        T1::INTERIOR_MUTABLE || T2::INTERIOR_MUTABLE || T3::INTERIOR_MUTABLE;
+
     fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "(")?;
         self.0.fmt_const_new(f)?;
@@ -64,6 +194,26 @@ where
         self.2.fmt_const_new(f)?;
         write!(f, ")")
     }
+
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        T1::fmt_type(f)?;
+        write!(f, ", ")?;
+        T2::fmt_type(f)?;
+        write!(f, ", ")?;
+        T3::fmt_type(f)?;
+        write!(f, ")")
+    }
+
+    fn fmt_static_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        T1::fmt_static_type(f)?;
+        write!(f, ", ")?;
+        T2::fmt_static_type(f)?;
+        write!(f, ", ")?;
+        T3::fmt_static_type(f)?;
+        write!(f, ")")
+    }
 }
 
 impl<T1, T2, T3, T4> ConstInstantiable for (T1, T2, T3, T4)
@@ -73,6 +223,11 @@ where
     T3: ConstInstantiable,
     T4: ConstInstantiable,
 {
+    const INTERIOR_MUTABLE: bool = T1::INTERIOR_MUTABLE
+        || T2::INTERIOR_MUTABLE
+        || T3::INTERIOR_MUTABLE
+        || T4::INTERIOR_MUTABLE;
+
     fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "(")?;
         self.0.fmt_const_new(f)?;
@@ -84,9 +239,62 @@ where
         self.3.fmt_const_new(f)?;
         write!(f, ")")
     }
+
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        T1::fmt_type(f)?;
+        write!(f, ", ")?;
+        T2::fmt_type(f)?;
+        write!(f, ", ")?;
+        T3::fmt_type(f)?;
+        write!(f, ", ")?;
+        T4::fmt_type(f)?;
+        write!(f, ")")
+    }
+
+    fn fmt_static_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "(")?;
+        T1::fmt_static_type(f)?;
+        write!(f, ", ")?;
+        T2::fmt_static_type(f)?;
+        write!(f, ", ")?;
+        T3::fmt_static_type(f)?;
+        write!(f, ", ")?;
+        T4::fmt_static_type(f)?;
+        write!(f, ")")
+    }
+}
+
+impl ConstInstantiable for quickphf::arena::Span {
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "::quickphf::arena::Span")
+    }
+
+    fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "::quickphf::arena::Span::new({}, {})",
+            self.start(),
+            self.len()
+        )
+    }
 }
 
 impl<T: ConstInstantiable> ConstInstantiable for Option<T> {
+    const INTERIOR_MUTABLE: bool = T::INTERIOR_MUTABLE;
+
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "::core::option::Option<")?;
+        T::fmt_type(f)?;
+        write!(f, ">")
+    }
+
+    fn fmt_static_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "::core::option::Option<")?;
+        T::fmt_static_type(f)?;
+        write!(f, ">")
+    }
+
     fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Some(x) => {
@@ -104,6 +312,24 @@ where
     T1: ConstInstantiable,
     T2: ConstInstantiable,
 {
+    const INTERIOR_MUTABLE: bool = T1::INTERIOR_MUTABLE || T2::INTERIOR_MUTABLE;
+
+    fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "::core::result::Result<")?;
+        T1::fmt_type(f)?;
+        write!(f, ", ")?;
+        T2::fmt_type(f)?;
+        write!(f, ">")
+    }
+
+    fn fmt_static_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "::core::result::Result<")?;
+        T1::fmt_static_type(f)?;
+        write!(f, ", ")?;
+        T2::fmt_static_type(f)?;
+        write!(f, ">")
+    }
+
     fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Ok(x) => {
@@ -119,3 +345,39 @@ where
         }
     }
 }
+
+macro_rules! impl_const_instantiable_for_atomic {
+    ($($atomic:ident),*) => {
+        $(
+            impl ConstInstantiable for $atomic {
+                const INTERIOR_MUTABLE: bool = true;
+
+                fn fmt_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str(concat!("::core::sync::atomic::", stringify!($atomic)))
+                }
+
+                fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(
+                        f,
+                        concat!("::core::sync::atomic::", stringify!($atomic), "::new({:?})"),
+                        self.load(Ordering::Relaxed)
+                    )
+                }
+            }
+        )*
+    };
+}
+
+impl_const_instantiable_for_atomic!(
+    AtomicBool,
+    AtomicI8,
+    AtomicI16,
+    AtomicI32,
+    AtomicI64,
+    AtomicIsize,
+    AtomicU8,
+    AtomicU16,
+    AtomicU32,
+    AtomicU64,
+    AtomicUsize
+);
@@ -17,9 +17,36 @@
 //! ## Example
 //!
 //! Currently, the only way to generate data structures for use with `quickphf`
-//! is by running one of [`build_raw_map`], [`build_map`], or [`build_set`],
-//! displaying the result as a string, and then importing the resulting Rust
-//! code at the desired location.
+//! is by running one of the `build_*` functions, displaying the result as a
+//! string, and then importing the resulting Rust code at the desired location.
+//! The following functions are available:
+//!
+//! - [`build_raw_map`] for a [`quickphf::RawPhfMap`],
+//! - [`build_packed_raw_map`] for a [`quickphf::PackedRawPhfMap`],
+//! - [`build_map`] for a [`quickphf::PhfMap`],
+//! - [`build_counter_map`] for a [`quickphf::PhfMap`] of [`AtomicU64`]
+//!   counters,
+//! - [`build_set`] for a [`quickphf::PhfSet`],
+//! - [`build_map_array`] for a [`quickphf::PhfMapArray`],
+//! - [`build_soa_map`] for a [`quickphf::SoaPhfMap`],
+//! - [`build_ordered_map`] for a [`quickphf::OrderedPhfMap`],
+//! - [`build_sorted_map`] for a [`quickphf::SortedPhfMap`],
+//! - [`build_sorted_set`] for a [`quickphf::SortedPhfSet`],
+//! - [`build_multimap`] for a [`quickphf::PhfMultiMap`],
+//! - [`build_bimap`] for a [`quickphf::PhfBiMap`],
+//! - [`build_dedup_map`] for a [`quickphf::DedupPhfMap`],
+//! - [`build_alias_map`] for a [`quickphf::AliasPhfMap`],
+//! - [`build_interner`] for a [`quickphf::PhfInterner`],
+//! - [`build_records`] for a [`quickphf::PhfRecords`],
+//! - [`build_columns`] for a [`quickphf::PhfColumns`],
+//! - [`build_arena_map`] and [`build_arena_span_map`] for a
+//!   [`quickphf::ArenaPhfMap`],
+//! - [`build_keyword_map`] for a [`quickphf::KeywordPhfMap`],
+//! - [`build_normalized_map`] for a [`quickphf::NormalizedPhfMap`],
+//! - [`build_blob_map`] for a [`quickphf::BlobPhfMap`],
+//! - [`build_static_map`] and [`build_static_map_as`] for a
+//!   [`quickphf::StaticMap`],
+//! - [`build_lookup_fn`] for a standalone lookup function.
 //!
 //! For example, you can write a
 //! [`build.rs` script](https://doc.rust-lang.org/cargo/reference/build-scripts.html)
@@ -52,6 +79,42 @@
 //!
 //! ## Advanced Usage
 //!
+//! ### Atomic values
+//!
+//! Maps can hold atomics such as [`AtomicU64`] as values, which allows them to
+//! be used as lock-free counters from many threads. Since values with interior
+//! mutability cannot be borrowed in a constant expression, the generated code
+//! is a block containing a named `static` for the entries, whose type is
+//! printed using [`ConstInstantiable::fmt_type`].
+//!
+//! ```ignore
+//! static COUNTERS: quickphf::PhfMap<&'static str, AtomicU64> =
+//!     include!(concat!(env!("OUT_DIR"), "/counters.rs"));
+//!
+//! COUNTERS.get("jpg").unwrap().fetch_add(1, Ordering::Relaxed);
+//! ```
+//!
+//! ### Large tables
+//!
+//! Compiling a table of millions of entries written as Rust literals is slow
+//! and takes a lot of memory. [`build_blob_map`] instead writes the tables to
+//! a binary file, which the generated code embeds with `include_bytes!`, and
+//! whose entries are decoded on every lookup. For a `u64` to `u64` map with
+//! 1,000,000 entries, this reduced the time of a debug build of the crate
+//! containing it from 7.3 to 0.2 seconds, and its peak memory usage from
+//! 1.4 GB to 120 MB.
+//!
+//! ```ignore
+//! let out_dir = env::var("OUT_DIR").unwrap();
+//! let blob_path = Path::new(&out_dir).join("ids.bin");
+//!
+//! let writer = quickphf_codegen::build_blob_map(&keys, &values);
+//! fs::write(&blob_path, writer.blob()).unwrap();
+//!
+//! let code = writer.code(blob_path.to_str().unwrap()).to_string();
+//! fs::write(Path::new(&out_dir).join("ids.rs"), code).unwrap();
+//! ```
+//!
 //! ### Using QuickPHF with custom types
 //!
 //! To be usable as a key in a `PhfMap` or `PhfSet`, or as value in a `RawPhfMap`
@@ -61,72 +124,84 @@
 //! but users can also implement it for their own custom types, by one of two ways:
 //!
 //! 1. If the code required to instantiate a value of a type is identical to its
-//! `Debug` representation, for example, like the following enum:
+//!    `Debug` representation, for example, like the following enum:
 //!
-//! ```ignore
-//! #[derive(Debug, Hash, PartialEq, Eq)]
-//! enum PositionType {
-//!     Contract { pub hours_per_week: u32 },
-//!     Salaried,
-//!     Managerial,
-//! }
-//! ```
+//!    ```ignore
+//!    #[derive(Debug, Hash, PartialEq, Eq)]
+//!    enum PositionType {
+//!        Contract { pub hours_per_week: u32 },
+//!        Salaried,
+//!        Managerial,
+//!    }
+//!    ```
 //!
-//! then it suffices to write
+//!    then it suffices to write
 //!
-//! ```ignore
-//! impl quickphf_codegen::DebugInstantiable for PositionType {}
-//! ```
+//!    ```ignore
+//!    impl quickphf_codegen::DebugInstantiable for PositionType {}
+//!    ```
 //!
 //! 2. Otherwise, the user has to provide a custom implementation. For example,
 //!    the following struct has private fields and thus its values cannot be
 //!    instantiated using the `{}` syntax, but provides a `new` constructor
 //!    that is a `const fn`. Thus, given
 //!
-//! ```ignore
-//! #[derive(Debug, Hash, PartialEq, Eq)]
-//! struct EmploymentRules {
-//!     overtime_eligible: bool,
-//!     bonus_eligible: bool,
-//! }
+//!    ```ignore
+//!    #[derive(Debug, Hash, PartialEq, Eq)]
+//!    struct EmploymentRules {
+//!        overtime_eligible: bool,
+//!        bonus_eligible: bool,
+//!    }
 //!
-//! impl EmploymentRules {
-//!     pub const fn new(overtime_eligible: bool, bonus_eligible: bool) -> EmploymentRules {
-//!         EmploymentRules {
-//!             overtime_eligible,
-//!             bonus_eligible,
-//!         }
-//!     }
-//! }
-//! ```
+//!    impl EmploymentRules {
+//!        pub const fn new(overtime_eligible: bool, bonus_eligible: bool) -> EmploymentRules {
+//!            EmploymentRules {
+//!                overtime_eligible,
+//!                bonus_eligible,
+//!            }
+//!        }
+//!    }
+//!    ```
 //!
-//! we can provide a custom `ConstInstantiable` implementation by
+//!    we can provide a custom `ConstInstantiable` implementation by
 //!
-//! ```ignore
-//! use core::fmt;
-//! use quickphf_codegen::*;
-//!
-//! impl ConstInstantiable for EmploymentRules {
-//!     fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-//!         write!(
-//!             f,
-//!             "EmploymentRules::new({}, {})",
-//!             self.overtime_eligible, self.bonus_eligible
-//!         )
-//!     }
-//! }
-//! ```
+//!    ```ignore
+//!    use core::fmt;
+//!    use quickphf_codegen::*;
+//!
+//!    impl ConstInstantiable for EmploymentRules {
+//!        fn fmt_const_new(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+//!            write!(
+//!                f,
+//!                "EmploymentRules::new({}, {})",
+//!                self.overtime_eligible, self.bonus_eligible
+//!            )
+//!        }
+//!    }
+//!    ```
 
 use core::fmt;
 use core::hash::Hash;
+use core::marker::PhantomData;
+use core::sync::atomic::AtomicU64;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
 
 use phf::{generate_phf, Phf};
+use quickphf::keyword_map::{project, MAX_POSITIONS};
+use quickphf::normalized_map::NormalizedKey;
+use quickphf::shared::magic_for;
 
 mod const_instantiable;
 pub mod phf;
 
 pub use const_instantiable::ConstInstantiable;
 pub use const_instantiable::DebugInstantiable;
+pub use quickphf::arena::Span;
+pub use quickphf::blob_map::FixedBytes;
+pub use quickphf::normalized_map::{AsciiCaseInsensitive, KeyNormalizer, Trim, UnicodeLowercase};
+pub use quickphf::packed::PackedValue;
+pub use quickphf::static_map::{Representation, StaticKey};
 
 /// Generate code for a static [`quickphf::RawPhfMap`].
 ///
@@ -150,10 +225,416 @@ pub fn build_raw_map<'a, K: Eq + Hash, V: ConstInstantiable>(
         kind: Kind::RawMap,
         phf,
         keys: &[],
+        values: Values::Borrowed(values),
+    }
+}
+
+/// Generate code for a static [`quickphf::PackedRawPhfMap`], whose values
+/// are packed into the minimal number of bits needed by the largest of them.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let months = [
+///     "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+/// ];
+/// let holidays: [u8; 12] = [2, 1, 0, 0, 0, 1, 1, 0, 1, 1, 2, 1];
+/// let holidays_per_month = build_packed_raw_map(&months, &holidays);
+///
+/// assert_eq!(holidays_per_month.bits(), 2);
+/// ```
+pub fn build_packed_raw_map<K: Eq + Hash, V: PackedValue>(
+    keys: &[K],
+    values: &[V],
+) -> PackedWriter {
+    let bits = values
+        .iter()
+        .map(|value| u32::BITS - value.to_bits().leading_zeros())
+        .max()
+        .unwrap_or(0);
+
+    let phf = generate_phf(keys);
+    let mut words = vec![0u64; (phf.map.len() * bits as usize + 63) / 64];
+    for (slot, &idx) in phf.map.iter().enumerate() {
+        let value = u64::from(values[idx as usize].to_bits());
+        let start = slot * bits as usize;
+        let (word, offset) = (start / 64, start % 64);
+
+        words[word] |= value << offset;
+        if offset + bits as usize > 64 {
+            words[word + 1] |= value >> (64 - offset);
+        }
+    }
+
+    PackedWriter { phf, bits, words }
+}
+
+/// Generate the binary tables and the code of a static
+/// [`quickphf::BlobPhfMap`], for tables too large to be compiled quickly as
+/// Rust literals.
+///
+/// The tables returned by [`BlobWriter::blob`] must be written to a file,
+/// whose path is then passed to [`BlobWriter::code`].
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let keys: Vec<u64> = (0..1000).map(|x| x * x).collect();
+/// let roots: Vec<u32> = (0..1000).collect();
+/// let writer = build_blob_map(&keys, &roots);
+///
+/// let path = std::env::temp_dir().join("square_roots.bin");
+/// std::fs::write(&path, writer.blob()).unwrap();
+/// let code = writer.code(path.to_str().unwrap()).to_string();
+/// ```
+pub fn build_blob_map<K, V>(keys: &[K], values: &[V]) -> BlobWriter
+where
+    K: Eq + Hash + FixedBytes,
+    V: FixedBytes,
+{
+    let phf = generate_phf(keys);
+
+    let entry_size = K::SIZE + V::SIZE;
+    let entries_offset = 2 * phf.pilots_table.len() + 4 * phf.free.len();
+    let mut blob = vec![0; entries_offset + entry_size * phf.map.len()];
+
+    for (pilot, bytes) in phf.pilots_table.iter().zip(blob.chunks_exact_mut(2)) {
+        pilot.write(bytes);
+    }
+
+    let free_bytes = &mut blob[2 * phf.pilots_table.len()..entries_offset];
+    for (slot, bytes) in phf.free.iter().zip(free_bytes.chunks_exact_mut(4)) {
+        slot.write(bytes);
+    }
+
+    if entry_size > 0 {
+        let entry_bytes = blob[entries_offset..].chunks_exact_mut(entry_size);
+        for (&idx, bytes) in phf.map.iter().zip(entry_bytes) {
+            let (key_bytes, value_bytes) = bytes.split_at_mut(K::SIZE);
+            keys[idx as usize].write(key_bytes);
+            values[idx as usize].write(value_bytes);
+        }
+    }
+
+    BlobWriter { phf, blob }
+}
+
+/// Generate code for a static [`quickphf::ArenaPhfMap`], which stores all of
+/// its keys in a single arena.
+///
+/// # Panics
+///
+/// Panics if the arena would be longer than `u32::MAX` bytes.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let countries = ["Argentina", "Brazil", "Chile"];
+/// let populations = [46, 216, 20];
+/// let code = build_arena_map(&countries, &populations);
+/// ```
+pub fn build_arena_map<'a, K, V>(keys: &'a [&'a K], values: &'a [V]) -> ArenaWriter<'a, K, V>
+where
+    K: ArenaKey + ?Sized,
+    V: ConstInstantiable,
+{
+    let mut arena = Arena::default();
+    let key_spans = keys.iter().map(|key| arena.push(key.as_bytes())).collect();
+
+    ArenaWriter {
+        phf: generate_phf(keys),
+        arena: arena.bytes,
+        key_spans,
+        values: Values::Borrowed(values),
+        key_marker: PhantomData,
+    }
+}
+
+/// Generate code for a static [`quickphf::ArenaPhfMap`], which stores both
+/// its keys and its values in a single arena, so that the values of the map
+/// are their [`Span`]s.
+///
+/// # Panics
+///
+/// Panics if the arena would be longer than `u32::MAX` bytes.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let countries = ["Argentina", "Brazil", "Chile"];
+/// let capitals = ["Buenos Aires", "Brasília", "Santiago"];
+/// let code = build_arena_span_map(&countries, &capitals);
+/// ```
+pub fn build_arena_span_map<'a, K: ArenaKey + ?Sized>(
+    keys: &'a [&'a K],
+    values: &'a [&'a K],
+) -> ArenaWriter<'a, K, Span> {
+    let mut arena = Arena::default();
+    let key_spans = keys.iter().map(|key| arena.push(key.as_bytes())).collect();
+    let value_spans = values
+        .iter()
+        .map(|value| arena.push(value.as_bytes()))
+        .collect();
+
+    ArenaWriter {
+        phf: generate_phf(keys),
+        arena: arena.bytes,
+        key_spans,
+        values: Values::Owned(value_spans),
+        key_marker: PhantomData,
+    }
+}
+
+/// A type of string which can be stored in the arena of a
+/// [`quickphf::ArenaPhfMap`].
+///
+/// It is implemented for `str` and `[u8]`.
+pub trait ArenaKey: Eq + Hash {
+    /// Returns the bytes of the string.
+    fn as_bytes(&self) -> &[u8];
+
+    /// Print a literal for the arena made up of the given bytes, which are a
+    /// concatenation of strings of this type.
+    #[allow(clippy::missing_errors_doc)]
+    fn fmt_arena(arena: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Print the type of a `'static` reference to a key, as it should appear
+    /// in a `static` item.
+    #[allow(clippy::missing_errors_doc)]
+    fn fmt_ref_type(f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl ArenaKey for str {
+    fn as_bytes(&self) -> &[u8] {
+        str::as_bytes(self)
+    }
+
+    fn fmt_arena(arena: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let arena = std::str::from_utf8(arena).map_err(|_| fmt::Error)?;
+        write!(f, "{:?}", arena)
+    }
+    fn fmt_ref_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "&str")
+    }
+}
+
+impl ArenaKey for [u8] {
+    fn as_bytes(&self) -> &[u8] {
+        self
+    }
+
+    fn fmt_arena(arena: &[u8], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "b\"")?;
+        for &byte in arena {
+            match byte {
+                b'"' | b'\\' => write!(f, "\\{}", byte as char)?,
+                b' '..=b'~' => write!(f, "{}", byte as char)?,
+                _ => write!(f, "\\x{:02x}", byte)?,
+            }
+        }
+        write!(f, "\"")
+    }
+
+    fn fmt_ref_type(f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "&[u8]")
+    }
+}
+
+/// Generate code for a static [`quickphf::KeywordPhfMap`], which only hashes
+/// the length of each key together with its bytes at a few positions.
+///
+/// The positions are chosen so that they distinguish all of the keys: every
+/// set of up to two positions is tried, and if none of them suffices,
+/// positions are added one at a time, each time choosing the one which
+/// distinguishes the most keys.
+///
+/// # Panics
+///
+/// Panics if `keys` contains duplicates, or if no set of up to
+/// [`MAX_POSITIONS`] positions distinguishes the keys.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let keywords = ["fn", "for", "if", "impl", "in", "let", "loop", "match"];
+/// let tokens: Vec<u8> = (0..8).collect();
+/// let code = build_keyword_map(&keywords, &tokens);
+///
+/// assert_eq!(code.positions(), &[0, 1]);
+/// ```
+pub fn build_keyword_map<'a, K, V>(keys: &'a [&'a K], values: &'a [V]) -> KeywordWriter<'a, K, V>
+where
+    K: ArenaKey + ?Sized,
+    V: ConstInstantiable,
+{
+    check_unique(keys, "key");
+
+    let positions = distinguishing_positions(keys);
+    let projections: Vec<u64> = keys
+        .iter()
+        .map(|key| project(key.as_bytes(), &positions))
+        .collect();
+    let phf = generate_phf(&projections);
+
+    KeywordWriter {
+        phf,
+        keys,
+        values,
+        positions,
+    }
+}
+
+/// Returns the smallest set of byte positions found which, together with their
+/// lengths, distinguishes all of the given distinct keys.
+fn distinguishing_positions<K: ArenaKey + ?Sized>(keys: &[&K]) -> Vec<u16> {
+    let distinguished = |positions: &[u16]| {
+        let projections: HashSet<u64> = keys
+            .iter()
+            .map(|key| project(key.as_bytes(), positions))
+            .collect();
+        projections.len()
+    };
+
+    let max_len = keys.iter().map(|key| key.as_bytes().len()).max();
+    let candidates = 0..max_len.unwrap_or(0).min(usize::from(u16::MAX)) as u16;
+
+    if distinguished(&[]) == keys.len() {
+        return Vec::new();
+    }
+    for first in candidates.clone() {
+        if distinguished(&[first]) == keys.len() {
+            return vec![first];
+        }
+    }
+    for first in candidates.clone() {
+        for second in first + 1..candidates.end {
+            if distinguished(&[first, second]) == keys.len() {
+                return vec![first, second];
+            }
+        }
+    }
+
+    let mut positions = Vec::new();
+    while positions.len() < MAX_POSITIONS {
+        let best = candidates
+            .clone()
+            .filter(|pos| !positions.contains(pos))
+            .max_by_key(|&pos| {
+                let mut extended = positions.clone();
+                extended.push(pos);
+                // Prefer earlier positions among equally good ones.
+                (distinguished(&extended), Reverse(pos))
+            });
+
+        match best {
+            Some(pos) => positions.push(pos),
+            None => break,
+        }
+
+        if distinguished(&positions) == keys.len() {
+            positions.sort_unstable();
+            return positions;
+        }
+    }
+
+    panic!(
+        "keys cannot be distinguished by their lengths and the bytes at {} positions",
+        MAX_POSITIONS
+    )
+}
+
+/// Generate code for a static [`quickphf::NormalizedPhfMap`], whose keys are
+/// normalized by `normalizer` both here and when being looked up.
+///
+/// The keys are stored in their normalized form.
+///
+/// # Panics
+///
+/// Panics if two keys have the same normalized form.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let headers = ["Accept", "Content-Length", "Content-Type", "Host"];
+/// let code = build_normalized_map(&headers, &[0, 1, 2, 3], AsciiCaseInsensitive);
+///
+/// assert!(code.to_string().contains("\"content-type\""));
+/// ```
+pub fn build_normalized_map<'a, N: KeyNormalizer, V: ConstInstantiable>(
+    keys: &[&str],
+    values: &'a [V],
+    _normalizer: N,
+) -> NormalizedWriter<'a, V> {
+    let normalized: Vec<NormalizedString> = keys
+        .iter()
+        .map(|key| {
+            let mut normalized = String::new();
+            N::normalize(key, |c| normalized.push(c));
+            NormalizedString(normalized)
+        })
+        .collect();
+
+    let mut first_index = HashMap::new();
+    for (idx, key) in normalized.iter().enumerate() {
+        if let Some(first) = first_index.insert(&key.0, idx) {
+            panic!(
+                "keys at indices {} and {} are equal after normalization",
+                first, idx
+            );
+        }
+    }
+
+    let phf = generate_phf(&normalized);
+    NormalizedWriter {
+        phf,
+        keys: normalized.into_iter().map(|key| key.0).collect(),
         values,
     }
 }
 
+/// A key which is already normalized, hashed like the keys looked up in a
+/// [`quickphf::NormalizedPhfMap`].
+#[derive(PartialEq, Eq)]
+struct NormalizedString(String);
+
+impl Hash for NormalizedString {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        NormalizedKey::<()>::new(&self.0).hash(state);
+    }
+}
+
+/// The bytes of an arena under construction, in which each distinct string is
+/// stored once.
+#[derive(Default)]
+struct Arena<'a> {
+    bytes: Vec<u8>,
+    spans: HashMap<&'a [u8], Span>,
+}
+
+impl<'a> Arena<'a> {
+    fn push(&mut self, string: &'a [u8]) -> Span {
+        let bytes = &mut self.bytes;
+        *self.spans.entry(string).or_insert_with(|| {
+            let start = u32::try_from(bytes.len()).expect("the arena is too long");
+            let len = u32::try_from(string.len()).expect("the arena is too long");
+            bytes.extend_from_slice(string);
+            Span::new(start, len)
+        })
+    }
+}
+
 /// Generate code for a static [`quickphf::PhfMap`].
 ///
 /// # Examples
@@ -174,121 +655,1880 @@ pub fn build_map<'a, K: Eq + Hash + ConstInstantiable, V: ConstInstantiable>(
         kind: Kind::Map,
         phf,
         keys,
-        values,
+        values: Values::Borrowed(values),
     }
 }
 
-/// Generate code for a static [`quickphf::PhfSet`].
+/// Generate code for a static [`quickphf::SoaPhfMap`], which stores its keys
+/// and its values in separate tables.
 ///
 /// # Examples
 ///
 /// ```
 /// use quickphf_codegen::*;
-/// let digits_set = build_set(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+///
+/// let codes = [200, 301, 404, 500];
+/// let reasons = ["OK", "Moved Permanently", "Not Found", "Internal Server Error"];
+/// let status_reasons = build_soa_map(&codes, &reasons);
 /// ```
-pub fn build_set<K: Eq + Hash + ConstInstantiable>(keys: &[K]) -> CodeWriter<'_, K> {
+pub fn build_soa_map<'a, K: Eq + Hash + ConstInstantiable, V: ConstInstantiable>(
+    keys: &'a [K],
+    values: &'a [V],
+) -> CodeWriter<'a, K, V> {
     let phf = generate_phf(keys);
     CodeWriter {
-        kind: Kind::Set,
+        kind: Kind::SoaMap,
         phf,
         keys,
-        values: &[],
+        values: Values::Borrowed(values),
     }
 }
 
-enum Kind {
-    RawMap,
-    Map,
-    Set,
+/// The largest number of keys for which [`build_static_map`] chooses the
+/// [`Representation::Linear`] representation.
+const LINEAR_MAX_LEN: usize = 8;
+
+/// Generate code for a static [`quickphf::StaticMap`], choosing the
+/// representation which best suits `keys`.
+///
+/// Keys forming a contiguous range of integers are stored by direct indexing,
+/// up to eight other keys in a slice searched linearly, and more keys in a
+/// [`quickphf::PhfMap`].
+///
+/// Integer keys whose range has holes are never stored by direct indexing,
+/// since the holes would need a separate table of occupied positions. The
+/// [`Representation::Sorted`] representation is never chosen automatically
+/// either, since a binary search is slower than a perfect hash lookup for
+/// maps too large to be searched linearly. It is only available through
+/// [`build_static_map_as`], for example to iterate over the entries in the
+/// order of their keys.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let opcodes: Vec<u8> = (0..=255).collect();
+/// let lengths: Vec<u8> = opcodes.iter().map(|op| op % 4 + 1).collect();
+/// let instruction_lengths = build_static_map(&opcodes, &lengths);
+/// assert_eq!(instruction_lengths.representation(), Representation::Direct);
+///
+/// let offsets = build_static_map(&[-2, -1, 0, 1, 2], &["--", "-", "0", "+", "++"]);
+/// assert_eq!(offsets.representation(), Representation::Direct);
+///
+/// let sparse = build_static_map(&[1, 10, 100, 1000], &[0, 1, 2, 3]);
+/// assert_eq!(sparse.representation(), Representation::Linear);
+/// ```
+pub fn build_static_map<'a, K, V>(keys: &'a [K], values: &'a [V]) -> StaticMapWriter<'a, K, V>
+where
+    K: StaticKey + ConstInstantiable,
+    V: ConstInstantiable,
+{
+    let representation = if contiguous_start(keys).is_some() {
+        Representation::Direct
+    } else if keys.len() <= LINEAR_MAX_LEN {
+        Representation::Linear
+    } else {
+        Representation::Phf
+    };
+
+    build_static_map_as(keys, values, representation)
 }
 
-/// Code generator for a PTHash perfect hash function hash table structure.
-pub struct CodeWriter<'a, K, V = ()> {
-    kind: Kind,
-    phf: Phf,
+/// Generate code for a static [`quickphf::StaticMap`] with the given
+/// representation.
+///
+/// # Panics
+///
+/// Panics if `keys` contains duplicates, or if the representation is
+/// [`Representation::Direct`] but `keys` do not form a contiguous range of
+/// integers.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let suits = ["clubs", "diamonds", "hearts", "spades"];
+/// let symbols = ['♣', '♦', '♥', '♠'];
+/// let suit_symbols = build_static_map_as(&suits, &symbols, Representation::Sorted);
+/// assert_eq!(suit_symbols.representation(), Representation::Sorted);
+///
+/// // Never chosen by `build_static_map`, even for keys with holes.
+/// let codes = [200, 201, 204, 206];
+/// let reasons = ["OK", "Created", "No Content", "Partial Content"];
+/// assert_eq!(build_static_map(&codes, &reasons).representation(), Representation::Linear);
+/// ```
+pub fn build_static_map_as<'a, K, V>(
     keys: &'a [K],
     values: &'a [V],
-}
+    representation: Representation,
+) -> StaticMapWriter<'a, K, V>
+where
+    K: StaticKey + ConstInstantiable,
+    V: ConstInstantiable,
+{
+    let mut start = 0;
+    let mut order: Vec<u32> = (0..keys.len() as u32).collect();
+    let mut phf = None;
 
-impl<'a, K: ConstInstantiable, V: ConstInstantiable> fmt::Display for CodeWriter<'a, K, V> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.write(f)
+    match representation {
+        Representation::Direct => {
+            start =
+                contiguous_start(keys).expect("keys do not form a contiguous range of integers");
+            order.sort_unstable_by_key(|&idx| {
+                keys[idx as usize].to_index().unwrap().wrapping_sub(start)
+            });
+        }
+        Representation::Linear => check_unique(keys, "key"),
+        Representation::Sorted => {
+            check_unique(keys, "key");
+            order.sort_unstable_by_key(|&idx| &keys[idx as usize]);
+        }
+        Representation::Phf => phf = Some(build_map(keys, values)),
     }
-}
 
-impl<'a, K: ConstInstantiable, V: ConstInstantiable> CodeWriter<'a, K, V> {
-    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let type_name = match self.kind {
-            Kind::RawMap => "RawPhfMap",
-            Kind::Map => "PhfMap",
-            Kind::Set => "PhfSet",
-        };
-
-        writeln!(f, "::quickphf::{}::new(", type_name)?;
-        writeln!(f, "    {},", self.phf.seed)?;
+    StaticMapWriter {
+        representation,
+        start,
+        keys,
+        values,
+        order,
+        phf,
+    }
+}
 
-        write!(f, "    &")?;
-        self.write_slice(self.phf.pilots_table.iter(), f)?;
-        writeln!(f, ",")?;
+/// Returns the integer corresponding to the smallest of the keys, if the keys
+/// correspond to a contiguous range of integers.
+fn contiguous_start<K: StaticKey>(keys: &[K]) -> Option<u64> {
+    let mut indices = keys
+        .iter()
+        .map(StaticKey::to_index)
+        .collect::<Option<Vec<u64>>>()?;
+    indices.sort_unstable();
 
-        //
-        let mut prev_entry = false;
-        write!(f, "    &")?;
-        write!(f, "[")?;
+    // Signed keys are mapped to integers modulo 2^64, so a range containing
+    // zero starts after the largest gap between consecutive integers.
+    let first = *indices.first()?;
+    let start = indices
+        .windows(2)
+        .find(|pair| pair[1] - pair[0] > 1)
+        .map_or(first, |pair| pair[1]);
 
-        for &idx in &self.phf.map {
-            if prev_entry {
-                write!(f, ", ")?;
-            } else {
-                prev_entry = true;
-            }
+    let contiguous = keys.iter().all(|key| {
+        let offset = key.to_index().unwrap().wrapping_sub(start);
+        offset < keys.len() as u64
+    });
+    let distinct = indices.windows(2).all(|pair| pair[0] != pair[1]);
 
-            match self.kind {
-                Kind::Map => {
-                    let key = &self.keys[idx as usize];
-                    let value = &self.values[idx as usize];
+    if contiguous && distinct {
+        Some(start)
+    } else {
+        None
+    }
+}
 
-                    write!(f, "(")?;
-                    key.fmt_const_new(f)?;
-                    write!(f, ", ")?;
-                    value.fmt_const_new(f)?;
-                    write!(f, ")")?;
-                }
-                Kind::RawMap => {
-                    self.values[idx as usize].fmt_const_new(f)?;
-                }
-                Kind::Set => {
-                    self.keys[idx as usize].fmt_const_new(f)?;
+/// Generate code for a standalone function named `name`, which looks up the
+/// value of a key in a perfect hash table of the given entries.
+///
+/// Unlike a [`quickphf::PhfMap`], whose parameters are loaded from the map at
+/// runtime, the generated function has its seed, table lengths and the magic
+/// numbers for its modulo reductions inlined as literals, so that they can be
+/// folded into the surrounding code by the optimizer. The tables themselves are
+/// `static` items inside the function.
+///
+/// The function has the signature
+///
+/// ```ignore
+/// pub fn name<Q>(key: &Q) -> Option<&'static V>
+/// where
+///     Q: Hash + Equivalent<K> + ?Sized;
+/// ```
+///
+/// in which the names of `K` and `V` are printed by
+/// [`ConstInstantiable::fmt_static_type`], and the type of the table of
+/// entries by [`ConstInstantiable::fmt_type`]. Displaying the generated code
+/// therefore panics for types which do not implement `fmt_type`.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let extensions = ["jpg", "png", "svg"];
+/// let mime_types = ["image/jpeg", "image/png", "image/svg+xml"];
+/// let code = build_lookup_fn("mime_type", &extensions, &mime_types).to_string();
+///
+/// assert!(code.starts_with("pub fn mime_type<Q>(key: &Q) -> Option<&'static &'static str>"));
+/// ```
+pub fn build_lookup_fn<'a, K, V>(
+    name: &'a str,
+    keys: &'a [K],
+    values: &'a [V],
+) -> LookupFnWriter<'a, K, V>
+where
+    K: Eq + Hash + ConstInstantiable,
+    V: ConstInstantiable,
+{
+    LookupFnWriter {
+        name,
+        code: build_map(keys, values),
+    }
+}
+
+/// Generate code for a [`quickphf::PhfMapArray`], which stores its tables
+/// inline and can thus be used in `const` contexts.
+///
+/// The const parameters of the resulting type can be obtained from
+/// [`MapArrayWriter::array_lengths`].
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let keys = [1, 2, 3, 4, 5];
+/// let squares = keys.map(|x| x * x);
+/// let squares_array = build_map_array(&keys, &squares);
+/// ```
+pub fn build_map_array<'a, K: Eq + Hash + ConstInstantiable, V: ConstInstantiable>(
+    keys: &'a [K],
+    values: &'a [V],
+) -> MapArrayWriter<'a, K, V> {
+    let phf = generate_phf(keys);
+    MapArrayWriter { phf, keys, values }
+}
+
+/// Generate code for a static [`quickphf::OrderedPhfMap`], which keeps its
+/// entries in the order of `keys` and `values`.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let planets = ["mercury", "venus", "earth", "mars"];
+/// let moons = [0, 0, 1, 2];
+/// let planet_moons = build_ordered_map(&planets, &moons);
+/// ```
+pub fn build_ordered_map<'a, K: Eq + Hash + ConstInstantiable, V: ConstInstantiable>(
+    keys: &'a [K],
+    values: &'a [V],
+) -> CodeWriter<'a, K, V> {
+    let phf = generate_phf(keys);
+    CodeWriter {
+        kind: Kind::OrderedMap,
+        phf,
+        keys,
+        values: Values::Borrowed(values),
+    }
+}
+
+/// Generate code for a static [`quickphf::PhfInterner`], which assigns each
+/// string the index of its position in `strings` as its symbol.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let keywords = ["fn", "let", "match", "struct"];
+/// let keyword_symbols = build_interner(&keywords);
+/// ```
+pub fn build_interner<'a>(strings: &'a [&'a str]) -> CodeWriter<'a, &'a str> {
+    let phf = generate_phf(strings);
+    CodeWriter {
+        kind: Kind::Interner,
+        phf,
+        keys: strings,
+        values: Values::Borrowed(&[]),
+    }
+}
+
+/// Generate code for a static [`quickphf::PhfMap`] whose values are
+/// [`AtomicU64`] counters, all initialized to zero.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let methods = ["GET", "HEAD", "POST", "PUT", "DELETE"];
+/// let requests_per_method = build_counter_map(&methods);
+///
+/// // Atomics cannot be borrowed in a constant expression, so the entries
+/// // are stored in a named `static`.
+/// assert!(requests_per_method
+///     .to_string()
+///     .contains("static ENTRIES: [(&str, ::core::sync::atomic::AtomicU64); 5]"));
+/// ```
+pub fn build_counter_map<K: Eq + Hash + ConstInstantiable>(
+    keys: &[K],
+) -> CodeWriter<'_, K, AtomicU64> {
+    let phf = generate_phf(keys);
+    CodeWriter {
+        kind: Kind::Map,
+        phf,
+        keys,
+        values: Values::Owned(keys.iter().map(|_| AtomicU64::new(0)).collect()),
+    }
+}
+
+/// Generate code for a static [`quickphf::PhfSet`].
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+/// let digits_set = build_set(&[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+/// ```
+pub fn build_set<K: Eq + Hash + ConstInstantiable>(keys: &[K]) -> CodeWriter<'_, K> {
+    let phf = generate_phf(keys);
+    CodeWriter {
+        kind: Kind::Set,
+        phf,
+        keys,
+        values: Values::Borrowed(&[]),
+    }
+}
+
+/// Generate code for a static [`quickphf::PhfColumns`] over `keys`, whose
+/// columns of values are then generated with [`CodeWriter::column`].
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let units = ["mm", "cm", "m", "km"];
+/// let factors = [1, 10, 1000, 1_000_000];
+/// let names = ["millimetre", "centimetre", "metre", "kilometre"];
+///
+/// let code = build_columns(&units);
+/// let factors_code = code.column(&factors).to_string();
+/// let names_code = code.column(&names).to_string();
+/// ```
+pub fn build_columns<K: Eq + Hash + ConstInstantiable>(keys: &[K]) -> CodeWriter<'_, K> {
+    let phf = generate_phf(keys);
+    CodeWriter {
+        kind: Kind::Columns,
+        phf,
+        keys,
+        values: Values::Borrowed(&[]),
+    }
+}
+
+/// Generate code for a static [`quickphf::PhfBiMap`], which can be queried
+/// both by the values of `lefts` and by those of `rights`.
+///
+/// # Panics
+///
+/// Panics if either `lefts` or `rights` contains a duplicate value.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let codes = [200, 201, 404];
+/// let reasons = ["OK", "Created", "Not Found"];
+/// let statuses = build_bimap(&codes, &reasons);
+/// ```
+pub fn build_bimap<'a, L, R>(lefts: &'a [L], rights: &'a [R]) -> BiMapWriter<'a, L, R>
+where
+    L: Eq + Hash + ConstInstantiable,
+    R: Eq + Hash + ConstInstantiable,
+{
+    check_unique(lefts, "left");
+    check_unique(rights, "right");
+
+    BiMapWriter {
+        left_phf: generate_phf(lefts),
+        right_phf: generate_phf(rights),
+        lefts,
+        rights,
+    }
+}
+
+/// Panics with a message naming `side` if `items` contains a duplicate.
+fn check_unique<T: Eq + Hash>(items: &[T], side: &str) {
+    let mut first_index = HashMap::new();
+    for (idx, item) in items.iter().enumerate() {
+        if let Some(first) = first_index.insert(item, idx) {
+            panic!("duplicate {} values at indices {} and {}", side, first, idx);
+        }
+    }
+}
+
+/// Generate code for a static [`quickphf::PhfRecords`] table of `records`,
+/// whose indexes are then added with [`RecordsWriter::index`].
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let countries = [("DE", "DEU", "Germany"), ("FR", "FRA", "France")];
+/// let code = build_records(&countries)
+///     .index("ISO2", |country| country.0)
+///     .index("ISO3", |country| country.1);
+/// ```
+pub fn build_records<T: ConstInstantiable>(records: &[T]) -> RecordsWriter<'_, T> {
+    RecordsWriter {
+        records,
+        names: Vec::new(),
+        indexes: Vec::new(),
+    }
+}
+
+/// Generate code for a static [`quickphf::DedupPhfMap`], which stores each
+/// distinct value of `values` only once.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let countries = ["DE", "FR", "US", "EC"];
+/// let currencies = ["EUR", "EUR", "USD", "USD"];
+/// let code = build_dedup_map(&countries, &currencies);
+/// ```
+pub fn build_dedup_map<'a, K, V>(keys: &'a [K], values: &'a [V]) -> DedupWriter<'a, K, V>
+where
+    K: Eq + Hash + ConstInstantiable,
+    V: Eq + Hash + ConstInstantiable,
+{
+    let mut target_of_value = HashMap::new();
+    let targets = values
+        .iter()
+        .map(|value| {
+            let distinct = target_of_value.len() as u32;
+            *target_of_value.entry(value).or_insert(distinct)
+        })
+        .collect();
+
+    let phf = generate_phf(keys);
+    DedupWriter {
+        phf,
+        keys,
+        values,
+        targets,
+    }
+}
+
+/// Generate code for a static [`quickphf::AliasPhfMap`], whose canonical
+/// entries are given by `keys` and `values`, and in which each pair of
+/// `aliases` makes its first key an alias of the canonical key that follows.
+///
+/// # Panics
+///
+/// Panics if a key occurs more than once among `keys` and the aliases, or if
+/// an alias refers to a key which is not in `keys`.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let zones = ["Europe/Kyiv", "Etc/UTC"];
+/// let offsets = [120, 0];
+/// let aliases = [("Europe/Kiev", "Europe/Kyiv"), ("UTC", "Etc/UTC")];
+/// let code = build_alias_map(&zones, &offsets, &aliases);
+/// ```
+pub fn build_alias_map<'a, K, V>(
+    keys: &'a [K],
+    values: &'a [V],
+    aliases: &'a [(K, K)],
+) -> AliasWriter<'a, K, V>
+where
+    K: Eq + Hash + ConstInstantiable,
+    V: ConstInstantiable,
+{
+    let all_keys: Vec<&K> = keys.iter().chain(aliases.iter().map(|a| &a.0)).collect();
+    check_unique(&all_keys, "key");
+
+    let canonical: HashMap<&K, u32> = keys.iter().zip(0..).collect();
+    let mut targets: Vec<u32> = (0..keys.len() as u32).collect();
+    for (idx, alias) in aliases.iter().enumerate() {
+        match canonical.get(&alias.1) {
+            Some(&target) => targets.push(target),
+            None => panic!(
+                "alias at index {} refers to a key which is not in `keys`",
+                idx
+            ),
+        }
+    }
+
+    let phf = generate_phf(&all_keys);
+    AliasWriter {
+        phf,
+        keys,
+        values,
+        aliases,
+        targets,
+    }
+}
+
+/// Generate code for a static [`quickphf::PhfMultiMap`], which maps each
+/// distinct key of `keys` to the slice of all its values in `values`.
+///
+/// Unlike the other `build_*` functions, `keys` may contain the same key more
+/// than once.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let extensions = ["js", "js", "png", "xml", "xml"];
+/// let mime_types = [
+///     "application/javascript",
+///     "text/javascript",
+///     "image/png",
+///     "application/xml",
+///     "text/xml",
+/// ];
+/// let code = build_multimap(&extensions, &mime_types);
+/// ```
+pub fn build_multimap<'a, K: Eq + Hash + ConstInstantiable, V: ConstInstantiable>(
+    keys: &'a [K],
+    values: &'a [V],
+) -> MultiMapWriter<'a, K, V> {
+    let mut group_of_key = HashMap::new();
+    let mut groups: Vec<Vec<u32>> = Vec::new();
+    for (idx, key) in keys.iter().enumerate() {
+        let group = *group_of_key.entry(key).or_insert_with(|| {
+            groups.push(Vec::new());
+            groups.len() - 1
+        });
+        groups[group].push(idx as u32);
+    }
+
+    let distinct_keys: Vec<&K> = groups.iter().map(|g| &keys[g[0] as usize]).collect();
+    let phf = generate_phf(&distinct_keys);
+    MultiMapWriter {
+        phf,
+        keys,
+        values,
+        groups,
+    }
+}
+
+/// Generate code for a static [`quickphf::SortedPhfMap`], which also supports
+/// ordered queries on its keys.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let elements = ["hydrogen", "helium", "lithium", "beryllium", "boron"];
+/// let numbers = [1, 2, 3, 4, 5];
+/// let elements_map = build_sorted_map(&elements, &numbers);
+/// ```
+pub fn build_sorted_map<'a, K: Ord + Hash + ConstInstantiable, V: ConstInstantiable>(
+    keys: &'a [K],
+    values: &'a [V],
+) -> SortedWriter<'a, K, V> {
+    let phf = generate_phf(keys);
+    let sorted = sorted_positions(&phf, keys);
+    SortedWriter {
+        phf,
+        keys,
+        values: Some(values),
+        sorted,
+    }
+}
+
+/// Generate code for a static [`quickphf::SortedPhfSet`], which also supports
+/// ordered queries on its elements.
+///
+/// # Examples
+///
+/// ```
+/// use quickphf_codegen::*;
+///
+/// let rainbow = build_sorted_set(&["red", "orange", "yellow", "green", "blue"]);
+/// ```
+pub fn build_sorted_set<K: Ord + Hash + ConstInstantiable>(keys: &[K]) -> SortedWriter<'_, K> {
+    let phf = generate_phf(keys);
+    let sorted = sorted_positions(&phf, keys);
+    SortedWriter {
+        phf,
+        keys,
+        values: None,
+        sorted,
+    }
+}
+
+/// Returns the positions of the table of entries, sorted by their keys.
+fn sorted_positions<K: Ord>(phf: &Phf, keys: &[K]) -> Vec<u32> {
+    let mut sorted: Vec<u32> = (0..phf.map.len() as u32).collect();
+    sorted.sort_unstable_by_key(|&pos| &keys[phf.map[pos as usize] as usize]);
+    sorted
+}
+
+enum Kind {
+    Columns,
+    Interner,
+    RawMap,
+    Map,
+    OrderedMap,
+    Set,
+    SoaMap,
+}
+
+enum Values<'a, V> {
+    Borrowed(&'a [V]),
+    Owned(Vec<V>),
+}
+
+impl<V> Values<'_, V> {
+    fn as_slice(&self) -> &[V] {
+        match self {
+            Values::Borrowed(values) => values,
+            Values::Owned(values) => values,
+        }
+    }
+}
+
+/// Code generator for a PTHash perfect hash function hash table structure.
+pub struct CodeWriter<'a, K, V = ()> {
+    kind: Kind,
+    phf: Phf,
+    keys: &'a [K],
+    values: Values<'a, V>,
+}
+
+impl<K: ConstInstantiable, V: ConstInstantiable> fmt::Display for CodeWriter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f)
+    }
+}
+
+impl<'a, K> CodeWriter<'a, K> {
+    /// Generate code for a column of a [`quickphf::PhfColumns`] produced by
+    /// [`build_columns`], which is a slice containing the value of each key
+    /// at its slot.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` does not have the same length as the keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf_codegen::*;
+    ///
+    /// let code = build_columns(&["mg", "g", "kg"]);
+    /// let factors = code.column(&[1, 1000, 1_000_000]);
+    ///
+    /// assert!(factors.to_string().starts_with("&["));
+    /// ```
+    pub fn column<'c, V>(&'c self, values: &'c [V]) -> ColumnWriter<'c, V> {
+        assert_eq!(
+            values.len(),
+            self.keys.len(),
+            "the column does not have a value for every key"
+        );
+
+        ColumnWriter {
+            order: &self.phf.map,
+            values,
+        }
+    }
+}
+
+/// Code generator for a [`quickphf::PhfMapArray`].
+pub struct MapArrayWriter<'a, K, V> {
+    phf: Phf,
+    keys: &'a [K],
+    values: &'a [V],
+}
+
+impl<K, V> MapArrayWriter<'_, K, V> {
+    /// Returns the lengths of the table of entries, the table of pilot
+    /// values and the table of free slots, which are the const parameters
+    /// `N`, `B` and `F` of a [`quickphf::PhfMapArray`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf_codegen::*;
+    ///
+    /// let keys = [1, 2, 3, 4, 5];
+    /// let squares = keys.map(|x| x * x);
+    /// let code = build_map_array(&keys, &squares);
+    ///
+    /// let (n, b, f) = code.array_lengths();
+    /// let item = format!("const SQUARES: PhfMapArray<u32, u32, {}, {}, {}> = {};", n, b, f, code);
+    /// ```
+    pub fn array_lengths(&self) -> (usize, usize, usize) {
+        (
+            self.phf.map.len(),
+            self.phf.pilots_table.len(),
+            self.phf.free.len(),
+        )
+    }
+}
+
+impl<K: ConstInstantiable, V: ConstInstantiable> fmt::Display for MapArrayWriter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // A `PhfMapArray` owns its tables, so they are written by value and
+        // never have to be placed in a `static`.
+        writeln!(f, "::quickphf::PhfMapArray::new(")?;
+        writeln!(f, "    {},", self.phf.seed)?;
+
+        write!(f, "    ")?;
+        write_slice(self.phf.pilots_table.iter(), f)?;
+        writeln!(f, ",")?;
+
+        write!(f, "    [")?;
+        for (pos, &idx) in self.phf.map.iter().enumerate() {
+            if pos > 0 {
+                write!(f, ", ")?;
+            }
+
+            write_pair(f, &self.keys[idx as usize], &self.values[idx as usize])?;
+        }
+        writeln!(f, "],")?;
+
+        write!(f, "    ")?;
+        write_slice(self.phf.free.iter(), f)?;
+        writeln!(f)?;
+
+        write!(f, ")")
+    }
+}
+
+/// Code generator for a [`quickphf::SortedPhfMap`] or a
+/// [`quickphf::SortedPhfSet`].
+pub struct SortedWriter<'a, K, V = ()> {
+    phf: Phf,
+    keys: &'a [K],
+    values: Option<&'a [V]>,
+    sorted: Vec<u32>,
+}
+
+impl<K: ConstInstantiable, V: ConstInstantiable> fmt::Display for SortedWriter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_entries = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "[")?;
+            for (pos, &idx) in self.phf.map.iter().enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
+                }
+
+                let key = &self.keys[idx as usize];
+                match self.values {
+                    Some(values) => write_pair(f, key, &values[idx as usize])?,
+                    None => key.fmt_const_new(f)?,
+                }
+            }
+            write!(f, "]")
+        };
+
+        let (type_name, entries) = match self.values {
+            Some(_) => (
+                "SortedPhfMap",
+                Table::new::<(K, V)>("ENTRIES", self.phf.map.len(), &write_entries),
+            ),
+            None => (
+                "SortedPhfSet",
+                Table::new::<K>("ENTRIES", self.phf.map.len(), &write_entries),
+            ),
+        };
+
+        write_block(f, &[&entries], |f| {
+            write_header(f, type_name, &self.phf)?;
+
+            write!(f, "    ")?;
+            entries.write_ref(f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.phf.free.iter(), f)?;
+            writeln!(f, ",")?;
+
+            // Sorted tables additionally store their positions in sorted order.
+            write!(f, "    &")?;
+            write_slice(self.sorted.iter(), f)?;
+            writeln!(f)?;
+
+            write!(f, ")")
+        })
+    }
+}
+
+/// Code generator for a [`quickphf::PhfMultiMap`].
+pub struct MultiMapWriter<'a, K, V> {
+    phf: Phf,
+    keys: &'a [K],
+    values: &'a [V],
+    groups: Vec<Vec<u32>>,
+}
+
+impl<K: ConstInstantiable, V: ConstInstantiable> fmt::Display for MultiMapWriter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Each entry holds a distinct key and the offset of its values.
+        let write_entries = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "[")?;
+            let mut offset = 0;
+            for (pos, &group) in self.phf.map.iter().enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
+                }
+
+                let group = &self.groups[group as usize];
+                write_pair(f, &self.keys[group[0] as usize], &(offset as u32))?;
+                offset += group.len();
+            }
+            write!(f, "]")
+        };
+
+        // The values of all keys are stored in a shared table, grouped by key
+        // in the order of the table of entries.
+        let write_values = |f: &mut fmt::Formatter<'_>| {
+            let grouped = self
+                .phf
+                .map
+                .iter()
+                .flat_map(|&group| &self.groups[group as usize])
+                .map(|&idx| &self.values[idx as usize]);
+            write_slice(grouped, f)
+        };
+
+        let entries = Table::new::<(K, u32)>("ENTRIES", self.phf.map.len(), &write_entries);
+        let values = Table::new::<V>("VALUES", self.values.len(), &write_values);
+
+        write_block(f, &[&entries, &values], |f| {
+            write_header(f, "PhfMultiMap", &self.phf)?;
+
+            write!(f, "    ")?;
+            entries.write_ref(f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.phf.free.iter(), f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    ")?;
+            values.write_ref(f)?;
+            writeln!(f)?;
+
+            write!(f, ")")
+        })
+    }
+}
+
+/// Code generator for a [`quickphf::PhfBiMap`].
+pub struct BiMapWriter<'a, L, R> {
+    left_phf: Phf,
+    right_phf: Phf,
+    lefts: &'a [L],
+    rights: &'a [R],
+}
+
+impl<L: ConstInstantiable, R: ConstInstantiable> fmt::Display for BiMapWriter<'_, L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_entries = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "[")?;
+            for (pos, &idx) in self.left_phf.map.iter().enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write_pair(f, &self.lefts[idx as usize], &self.rights[idx as usize])?;
+            }
+            write!(f, "]")
+        };
+
+        let entries = Table::new::<(L, R)>("ENTRIES", self.left_phf.map.len(), &write_entries);
+
+        write_block(f, &[&entries], |f| {
+            write_header(f, "PhfBiMap", &self.left_phf)?;
+
+            write!(f, "    ")?;
+            entries.write_ref(f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.left_phf.free.iter(), f)?;
+            writeln!(f, ",")?;
+
+            // The perfect hash function for the right values maps them to
+            // the positions of their entries.
+            let mut positions = vec![0; self.left_phf.map.len()];
+            for (pos, &idx) in self.left_phf.map.iter().enumerate() {
+                positions[idx as usize] = pos as u32;
+            }
+
+            writeln!(f, "    {},", self.right_phf.seed)?;
+            write!(f, "    &")?;
+            write_slice(self.right_phf.pilots_table.iter(), f)?;
+            writeln!(f, ",")?;
+            write!(f, "    &")?;
+            let indices: Vec<u32> = self
+                .right_phf
+                .map
+                .iter()
+                .map(|&idx| positions[idx as usize])
+                .collect();
+            write_slice(indices.iter(), f)?;
+            writeln!(f, ",")?;
+            write!(f, "    &")?;
+            write_slice(self.right_phf.free.iter(), f)?;
+            writeln!(f)?;
+
+            write!(f, ")")
+        })
+    }
+}
+
+/// Code generator for a [`quickphf::DedupPhfMap`].
+pub struct DedupWriter<'a, K, V> {
+    phf: Phf,
+    keys: &'a [K],
+    values: &'a [V],
+    targets: Vec<u32>,
+}
+
+impl<K: ConstInstantiable, V: ConstInstantiable> fmt::Display for DedupWriter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_entries = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "[")?;
+            for (pos, &idx) in self.phf.map.iter().enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write_pair(f, &self.keys[idx as usize], &self.targets[idx as usize])?;
+            }
+            write!(f, "]")
+        };
+
+        // Each distinct value is stored once, in the order of their first
+        // occurrences, which are the values whose target is the number of
+        // distinct values seen before them.
+        let mut distinct = 0;
+        let first_occurrences: Vec<&V> = self
+            .targets
+            .iter()
+            .zip(self.values)
+            .filter(|&(&target, _)| {
+                let first = target == distinct;
+                if first {
+                    distinct += 1;
+                }
+                first
+            })
+            .map(|(_, value)| value)
+            .collect();
+        let write_values =
+            |f: &mut fmt::Formatter<'_>| write_slice(first_occurrences.iter().copied(), f);
+
+        let entries = Table::new::<(K, u32)>("ENTRIES", self.phf.map.len(), &write_entries);
+        let values = Table::new::<V>("VALUES", first_occurrences.len(), &write_values);
+
+        write_block(f, &[&entries, &values], |f| {
+            write_header(f, "DedupPhfMap", &self.phf)?;
+
+            write!(f, "    ")?;
+            entries.write_ref(f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.phf.free.iter(), f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    ")?;
+            values.write_ref(f)?;
+            writeln!(f)?;
+
+            write!(f, ")")
+        })
+    }
+}
+
+/// Code generator for an [`quickphf::AliasPhfMap`].
+pub struct AliasWriter<'a, K, V> {
+    phf: Phf,
+    keys: &'a [K],
+    values: &'a [V],
+    aliases: &'a [(K, K)],
+    targets: Vec<u32>,
+}
+
+impl<K: ConstInstantiable, V: ConstInstantiable> fmt::Display for AliasWriter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Every key, canonical or alias, is stored with the position of its
+        // canonical entry.
+        let write_keys = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "[")?;
+            for (pos, &idx) in self.phf.map.iter().enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
+                }
+
+                let key = match idx.checked_sub(self.keys.len() as u32) {
+                    Some(alias) => &self.aliases[alias as usize].0,
+                    None => &self.keys[idx as usize],
+                };
+                write_pair(f, key, &self.targets[idx as usize])?;
+            }
+            write!(f, "]")
+        };
+
+        // The canonical entries are stored in their original order.
+        let write_entries = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "[")?;
+            for (pos, (key, value)) in self.keys.iter().zip(self.values).enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write_pair(f, key, value)?;
+            }
+            write!(f, "]")
+        };
+
+        let keys = Table::new::<(K, u32)>("KEYS", self.phf.map.len(), &write_keys);
+        let entries = Table::new::<(K, V)>("ENTRIES", self.keys.len(), &write_entries);
+
+        write_block(f, &[&keys, &entries], |f| {
+            write_header(f, "AliasPhfMap", &self.phf)?;
+
+            write!(f, "    ")?;
+            keys.write_ref(f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.phf.free.iter(), f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    ")?;
+            entries.write_ref(f)?;
+            writeln!(f)?;
+
+            write!(f, ")")
+        })
+    }
+}
+
+/// Code generator for a [`quickphf::ArenaPhfMap`].
+pub struct ArenaWriter<'a, K: ?Sized, V> {
+    phf: Phf,
+    arena: Vec<u8>,
+    key_spans: Vec<Span>,
+    values: Values<'a, V>,
+    key_marker: PhantomData<&'a K>,
+}
+
+impl<K: ArenaKey + ?Sized, V: ConstInstantiable> fmt::Display for ArenaWriter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_entries = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "[")?;
+            for (pos, &idx) in self.phf.map.iter().enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
                 }
+
+                let value = &self.values.as_slice()[idx as usize];
+                write_pair(f, &self.key_spans[idx as usize], value)?;
             }
-        }
-        writeln!(f, "],")?;
+            write!(f, "]")
+        };
+        let entries = Table::new::<(Span, V)>("ENTRIES", self.phf.map.len(), &write_entries);
+
+        write_block(f, &[&entries], |f| {
+            write_header(f, "ArenaPhfMap", &self.phf)?;
+
+            write!(f, "    ")?;
+            entries.write_ref(f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.phf.free.iter(), f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    ")?;
+            K::fmt_arena(&self.arena, f)?;
+            writeln!(f)?;
+
+            write!(f, ")")
+        })
+    }
+}
+
+/// Code generator for a [`quickphf::PackedRawPhfMap`].
+pub struct PackedWriter {
+    phf: Phf,
+    bits: u32,
+    words: Vec<u64>,
+}
+
+impl PackedWriter {
+    /// Returns the number of bits taken up by each value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf_codegen::*;
+    ///
+    /// let code = build_packed_raw_map(&["off", "on"], &[false, true]);
+    /// assert_eq!(code.bits(), 1);
+    /// ```
+    pub fn bits(&self) -> u32 {
+        self.bits
+    }
+}
+
+impl fmt::Display for PackedWriter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "::quickphf::PackedRawPhfMap::new(")?;
+        writeln!(f, "    {},", self.phf.seed)?;
+
+        write!(f, "    &")?;
+        write_slice(self.phf.pilots_table.iter(), f)?;
+        writeln!(f, ",")?;
+
+        // The map only needs the number of slots, which is given by a slice of
+        // zero-sized values.
+        writeln!(f, "    &[(); {}],", self.phf.map.len())?;
+
+        write!(f, "    &")?;
+        write_slice(self.phf.free.iter(), f)?;
+        writeln!(f, ",")?;
+
+        writeln!(f, "    {},", self.bits)?;
 
         write!(f, "    &")?;
-        self.write_slice(self.phf.free.iter(), f)?;
+        write_slice(self.words.iter(), f)?;
         writeln!(f)?;
 
         write!(f, ")")
     }
+}
+
+/// Generator of the binary tables and the code of a [`quickphf::BlobPhfMap`].
+pub struct BlobWriter {
+    phf: Phf,
+    blob: Vec<u8>,
+}
+
+impl BlobWriter {
+    /// Returns the binary tables of the map, which must be written to the
+    /// file included by the generated code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf_codegen::*;
+    ///
+    /// let writer = build_blob_map(&[1u64, 2, 3], &[true, false, true]);
+    /// assert!(writer.blob().len() >= 3 * 9);
+    /// ```
+    pub fn blob(&self) -> &[u8] {
+        &self.blob
+    }
+
+    /// Returns the code of the map, which embeds the file at `path`
+    /// containing the tables returned by [`blob`](BlobWriter::blob).
+    ///
+    /// The path is interpreted by `include_bytes!`, so it is either absolute
+    /// or relative to the file containing the code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf_codegen::*;
+    ///
+    /// let writer = build_blob_map(&[1u64, 2, 3], &[true, false, true]);
+    /// let code = writer.code("flags.bin").to_string();
+    ///
+    /// assert!(code.contains("include_bytes!(\"flags.bin\")"));
+    /// ```
+    pub fn code<'a>(&'a self, path: &'a str) -> BlobCode<'a> {
+        BlobCode { writer: self, path }
+    }
+}
+
+/// Code of a [`quickphf::BlobPhfMap`] embedding its tables from a file.
+pub struct BlobCode<'a> {
+    writer: &'a BlobWriter,
+    path: &'a str,
+}
+
+impl<'a> fmt::Display for BlobCode<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phf = &self.writer.phf;
+
+        writeln!(f, "::quickphf::BlobPhfMap::new(")?;
+        writeln!(f, "    {},", phf.seed)?;
+        writeln!(f, "    {},", phf.map.len())?;
+        writeln!(f, "    {},", phf.pilots_table.len())?;
+        writeln!(f, "    {},", phf.free.len())?;
+        writeln!(f, "    include_bytes!({:?})", self.path)?;
+        write!(f, ")")
+    }
+}
+
+/// Code generator for a [`quickphf::StaticMap`].
+pub struct StaticMapWriter<'a, K, V> {
+    representation: Representation,
+    start: u64,
+    keys: &'a [K],
+    values: &'a [V],
+    order: Vec<u32>,
+    phf: Option<CodeWriter<'a, K, V>>,
+}
+
+impl<'a, K, V> StaticMapWriter<'a, K, V> {
+    /// Returns the representation of the generated map.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf_codegen::*;
+    ///
+    /// let code = build_static_map(&["yes", "no"], &[true, false]);
+    /// assert_eq!(code.representation(), Representation::Linear);
+    /// ```
+    pub fn representation(&self) -> Representation {
+        self.representation
+    }
+}
+
+impl<'a, K: ConstInstantiable, V: ConstInstantiable> fmt::Display for StaticMapWriter<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(phf) = &self.phf {
+            return write!(f, "::quickphf::StaticMap::phf({})", phf);
+        }
+
+        let write_entries = |f: &mut fmt::Formatter<'_>| self.write_entries(f);
+        let entries = Table::new::<(K, V)>("ENTRIES", self.keys.len(), &write_entries);
+
+        write_block(f, &[&entries], |f| {
+            match self.representation {
+                Representation::Direct => {
+                    write!(f, "::quickphf::StaticMap::direct({}, ", self.start)?;
+                }
+                Representation::Linear => write!(f, "::quickphf::StaticMap::linear(")?,
+                Representation::Sorted => write!(f, "::quickphf::StaticMap::sorted(")?,
+                Representation::Phf => unreachable!(),
+            }
 
-    fn write_slice<T: ConstInstantiable + 'a>(
-        &'a self,
-        entries: impl Iterator<Item = &'a T>,
-        f: &mut fmt::Formatter<'_>,
-    ) -> fmt::Result {
+            entries.write_ref(f)?;
+            write!(f, ")")
+        })
+    }
+}
+
+impl<'a, K: ConstInstantiable, V: ConstInstantiable> StaticMapWriter<'a, K, V> {
+    fn write_entries(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "[")?;
+        for (pos, &idx) in self.order.iter().enumerate() {
+            if pos > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "(")?;
+            self.keys[idx as usize].fmt_const_new(f)?;
+            write!(f, ", ")?;
+            self.values[idx as usize].fmt_const_new(f)?;
+            write!(f, ")")?;
+        }
+        write!(f, "]")
+    }
+}
+
+/// Code generator for a standalone lookup function.
+pub struct LookupFnWriter<'a, K, V> {
+    name: &'a str,
+    code: CodeWriter<'a, K, V>,
+}
+
+impl<'a, K: ConstInstantiable, V: ConstInstantiable> fmt::Display for LookupFnWriter<'a, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let phf = &self.code.phf;
+        let len = phf.map.len();
+
+        writeln!(
+            f,
+            "pub fn {}<Q>(key: &Q) -> Option<&'static {}>",
+            self.name,
+            StaticTypeName::<V>(PhantomData)
+        )?;
+        writeln!(f, "where")?;
+        writeln!(
+            f,
+            "    Q: ::core::hash::Hash + ::quickphf::Equivalent<{}> + ?Sized,",
+            StaticTypeName::<K>(PhantomData)
+        )?;
+        writeln!(f, "{{")?;
+
+        if len == 0 {
+            writeln!(f, "    let _ = key;")?;
+            writeln!(f, "    None")?;
+            return write!(f, "}}");
+        }
+
+        write!(f, "    static PILOTS: [u16; {}] = ", phf.pilots_table.len())?;
+        write_slice(phf.pilots_table.iter(), f)?;
+        writeln!(f, ";")?;
+
+        write!(f, "    static FREE: [u32; {}] = ", phf.free.len())?;
+        write_slice(phf.free.iter(), f)?;
+        writeln!(f, ";")?;
+
+        write!(f, "    static ENTRIES: [")?;
+        <(K, V)>::fmt_type(f)?;
+        write!(f, "; {}] = ", len)?;
+        self.code.write_entries(f)?;
+        writeln!(f, ";")?;
+        writeln!(f)?;
+
+        let buckets = phf.pilots_table.len() as u64;
+        let codomain_len = (len + phf.free.len()) as u64;
+
+        writeln!(
+            f,
+            "    let hash = ::quickphf::shared::hash_key(key, {});",
+            phf.seed
+        )?;
+        writeln!(
+            f,
+            "    let bucket = ::quickphf::shared::rem_with_magic(hash, {}, {}) as usize;",
+            magic_for(buckets),
+            buckets
+        )?;
+        writeln!(
+            f,
+            "    let pilot_hash = ::quickphf::shared::hash_pilot_value(PILOTS[bucket]);"
+        )?;
+        writeln!(
+            f,
+            "    let idx = ::quickphf::shared::rem_with_magic(hash ^ pilot_hash, {}, {}) as usize;",
+            magic_for(codomain_len),
+            codomain_len
+        )?;
+        writeln!(
+            f,
+            "    let slot = if idx < {} {{ idx }} else {{ FREE[idx - {}] as usize }};",
+            len, len
+        )?;
+        writeln!(f)?;
+        writeln!(f, "    let entry = &ENTRIES[slot];")?;
+        writeln!(
+            f,
+            "    if ::quickphf::Equivalent::equivalent(key, &entry.0) {{"
+        )?;
+        writeln!(f, "        Some(&entry.1)")?;
+        writeln!(f, "    }} else {{")?;
+        writeln!(f, "        None")?;
+        writeln!(f, "    }}")?;
+        write!(f, "}}")
+    }
+}
+
+/// The name of a type as printed by [`ConstInstantiable::fmt_static_type`],
+/// so that it can appear in the signature of a function.
+struct StaticTypeName<T>(PhantomData<T>);
+
+impl<T: ConstInstantiable> fmt::Display for StaticTypeName<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        T::fmt_static_type(f)
+    }
+}
+
+/// Code generator for a [`quickphf::KeywordPhfMap`].
+pub struct KeywordWriter<'a, K: ?Sized, V> {
+    phf: Phf,
+    keys: &'a [&'a K],
+    values: &'a [V],
+    positions: Vec<u16>,
+}
+
+impl<'a, K: ?Sized, V> KeywordWriter<'a, K, V> {
+    /// Returns the positions of the bytes which are hashed along with the
+    /// length of a key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf_codegen::*;
+    ///
+    /// let code = build_keyword_map(&["cat", "cot", "cut"], &[1, 2, 3]);
+    /// assert_eq!(code.positions(), &[1]);
+    /// ```
+    pub fn positions(&self) -> &[u16] {
+        &self.positions
+    }
+}
+
+impl<K: ArenaKey + ?Sized, V: ConstInstantiable> fmt::Display for KeywordWriter<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_entries = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "[")?;
+            for (pos, &idx) in self.phf.map.iter().enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
+                }
+
+                write!(f, "(")?;
+                K::fmt_arena(self.keys[idx as usize].as_bytes(), f)?;
+                write!(f, ", ")?;
+                self.values[idx as usize].fmt_const_new(f)?;
+                write!(f, ")")?;
+            }
+            write!(f, "]")
+        };
+        let fmt_type = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "(")?;
+            K::fmt_ref_type(f)?;
+            write!(f, ", ")?;
+            V::fmt_type(f)?;
+            write!(f, ")")
+        };
+        let entries = Table::with_type(
+            "ENTRIES",
+            self.phf.map.len(),
+            V::INTERIOR_MUTABLE,
+            fmt_type,
+            &write_entries,
+        );
+
+        write_block(f, &[&entries], |f| {
+            write_header(f, "KeywordPhfMap", &self.phf)?;
+
+            write!(f, "    ")?;
+            entries.write_ref(f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.phf.free.iter(), f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.positions.iter(), f)?;
+            writeln!(f)?;
+
+            write!(f, ")")
+        })
+    }
+}
+
+/// Code generator for a [`quickphf::NormalizedPhfMap`].
+pub struct NormalizedWriter<'a, V> {
+    phf: Phf,
+    keys: Vec<String>,
+    values: &'a [V],
+}
+
+impl<V: ConstInstantiable> fmt::Display for NormalizedWriter<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_entries = |f: &mut fmt::Formatter<'_>| {
+            write!(f, "[")?;
+            for (pos, &idx) in self.phf.map.iter().enumerate() {
+                if pos > 0 {
+                    write!(f, ", ")?;
+                }
+
+                let value = &self.values[idx as usize];
+                write_pair(f, &self.keys[idx as usize].as_str(), value)?;
+            }
+            write!(f, "]")
+        };
+        let entries = Table::new::<(&str, V)>("ENTRIES", self.phf.map.len(), &write_entries);
+
+        write_block(f, &[&entries], |f| {
+            write_header(f, "NormalizedPhfMap", &self.phf)?;
+
+            write!(f, "    ")?;
+            entries.write_ref(f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.phf.free.iter(), f)?;
+            writeln!(f)?;
+
+            write!(f, ")")
+        })
+    }
+}
+
+/// Code generator for a column of values of a [`quickphf::PhfColumns`].
+pub struct ColumnWriter<'a, V> {
+    order: &'a [u32],
+    values: &'a [V],
+}
+
+impl<V: ConstInstantiable> fmt::Display for ColumnWriter<'_, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let write_column = |f: &mut fmt::Formatter<'_>| {
+            write_slice(self.order.iter().map(|&idx| &self.values[idx as usize]), f)
+        };
+        let column = Table::new::<V>("COLUMN", self.order.len(), &write_column);
+
+        write_block(f, &[&column], |f| column.write_ref(f))
+    }
+}
+
+impl<K: ConstInstantiable, V: ConstInstantiable> CodeWriter<'_, K, V> {
+    fn write(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let type_name = match self.kind {
+            Kind::Columns => "PhfColumns",
+            Kind::Interner => "PhfInterner",
+            Kind::RawMap => "RawPhfMap",
+            Kind::Map => "PhfMap",
+            Kind::OrderedMap => "OrderedPhfMap",
+            Kind::Set => "PhfSet",
+            Kind::SoaMap => "SoaPhfMap",
+        };
+
+        let len = self.phf.map.len();
+        let write_entries = |f: &mut fmt::Formatter<'_>| self.write_entries(f);
+        let entries = match self.kind {
+            Kind::Map | Kind::OrderedMap => Table::new::<(K, V)>("ENTRIES", len, &write_entries),
+            Kind::RawMap => Table::new::<V>("ENTRIES", len, &write_entries),
+            Kind::Columns | Kind::Interner | Kind::Set | Kind::SoaMap => {
+                Table::new::<K>("ENTRIES", len, &write_entries)
+            }
+        };
+
+        // A `SoaPhfMap` stores its values in a separate table, in the same
+        // order as its keys.
+        let write_values = |f: &mut fmt::Formatter<'_>| {
+            let values = self.values.as_slice();
+            write_slice(self.phf.map.iter().map(|&idx| &values[idx as usize]), f)
+        };
+        let values = Table::new::<V>("VALUES", len, &write_values);
+
+        let soa_tables = [&entries, &values];
+        let map_tables = [&entries];
+        let tables: &[&Table<'_>] = match self.kind {
+            Kind::SoaMap => &soa_tables,
+            _ => &map_tables,
+        };
+
+        write_block(f, tables, |f| {
+            write_header(f, type_name, &self.phf)?;
+
+            // An `OrderedPhfMap` and a `PhfInterner` keep their entries in their
+            // original order, and map slots of the codomain to them through a
+            // table of indices.
+            if let Kind::Interner | Kind::OrderedMap = self.kind {
+                write!(f, "    &")?;
+                write_slice(self.phf.map.iter(), f)?;
+                writeln!(f, ",")?;
+            }
+
+            write!(f, "    ")?;
+            entries.write_ref(f)?;
+            writeln!(f, ",")?;
+
+            write!(f, "    &")?;
+            write_slice(self.phf.free.iter(), f)?;
+
+            if let Kind::SoaMap = self.kind {
+                writeln!(f, ",")?;
+                write!(f, "    ")?;
+                values.write_ref(f)?;
+            }
+            writeln!(f)?;
+
+            write!(f, ")")
+        })
+    }
 
+    fn write_entries(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let mut prev_entry = false;
-        for entry in entries {
+        write!(f, "[")?;
+
+        let order: Box<dyn Iterator<Item = u32> + '_> = match self.kind {
+            Kind::Interner | Kind::OrderedMap => Box::new(0..self.phf.map.len() as u32),
+            _ => Box::new(self.phf.map.iter().copied()),
+        };
+
+        for idx in order {
             if prev_entry {
                 write!(f, ", ")?;
             } else {
                 prev_entry = true;
             }
 
-            entry.fmt_const_new(f)?;
+            match self.kind {
+                Kind::Map | Kind::OrderedMap => {
+                    let key = &self.keys[idx as usize];
+                    let value = &self.values.as_slice()[idx as usize];
+                    write_pair(f, key, value)?;
+                }
+                Kind::RawMap => {
+                    self.values.as_slice()[idx as usize].fmt_const_new(f)?;
+                }
+                Kind::Columns | Kind::Interner | Kind::Set | Kind::SoaMap => {
+                    self.keys[idx as usize].fmt_const_new(f)?;
+                }
+            }
         }
 
         write!(f, "]")
     }
 }
+
+/// A table of the generated code, which is written inline unless its elements
+/// have interior mutability. Since such values cannot be borrowed in a
+/// constant expression, the table is then placed in a named `static`, whose
+/// type is printed using [`ConstInstantiable::fmt_type`].
+struct Table<'t> {
+    name: &'static str,
+    len: usize,
+    fmt_type: Option<fn(&mut fmt::Formatter<'_>) -> fmt::Result>,
+    write_entries: &'t dyn Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+}
+
+impl<'t> Table<'t> {
+    fn new<T: ConstInstantiable>(
+        name: &'static str,
+        len: usize,
+        write_entries: &'t dyn Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    ) -> Table<'t> {
+        Table::with_type(name, len, T::INTERIOR_MUTABLE, T::fmt_type, write_entries)
+    }
+
+    /// Creates a table whose element type is printed by `fmt_type`, for
+    /// elements which are not written as a single `ConstInstantiable` value.
+    fn with_type(
+        name: &'static str,
+        len: usize,
+        interior_mutable: bool,
+        fmt_type: fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+        write_entries: &'t dyn Fn(&mut fmt::Formatter<'_>) -> fmt::Result,
+    ) -> Table<'t> {
+        Table {
+            name,
+            len,
+            fmt_type: if interior_mutable {
+                Some(fmt_type)
+            } else {
+                None
+            },
+            write_entries,
+        }
+    }
+
+    fn write_static(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(fmt_type) = self.fmt_type {
+            write!(f, "    static {}: [", self.name)?;
+            fmt_type(f)?;
+            write!(f, "; {}] = ", self.len)?;
+            (self.write_entries)(f)?;
+            writeln!(f, ";")?;
+        }
+
+        Ok(())
+    }
+
+    fn write_ref(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "&")?;
+        if self.fmt_type.is_some() {
+            f.write_str(self.name)
+        } else {
+            (self.write_entries)(f)
+        }
+    }
+}
+
+/// Writes the expression printed by `write_expr`, preceded by the `static`
+/// items of those `tables` which need one, in which case both are wrapped
+/// in a block.
+fn write_block(
+    f: &mut fmt::Formatter<'_>,
+    tables: &[&Table<'_>],
+    write_expr: impl FnOnce(&mut fmt::Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let has_statics = tables.iter().any(|table| table.fmt_type.is_some());
+
+    if has_statics {
+        writeln!(f, "{{")?;
+        for table in tables {
+            table.write_static(f)?;
+        }
+        writeln!(f)?;
+    }
+
+    write_expr(f)?;
+
+    if has_statics {
+        writeln!(f)?;
+        write!(f, "}}")?;
+    }
+
+    Ok(())
+}
+
+/// Writes the start of a call to the constructor of `type_name`, up to and
+/// including the table of pilot values of `phf`.
+fn write_header(f: &mut fmt::Formatter<'_>, type_name: &str, phf: &Phf) -> fmt::Result {
+    writeln!(f, "::quickphf::{}::new(", type_name)?;
+    writeln!(f, "    {},", phf.seed)?;
+
+    write!(f, "    &")?;
+    write_slice(phf.pilots_table.iter(), f)?;
+    writeln!(f, ",")
+}
+
+fn write_pair<A: ConstInstantiable, B: ConstInstantiable>(
+    f: &mut fmt::Formatter<'_>,
+    first: &A,
+    second: &B,
+) -> fmt::Result {
+    write!(f, "(")?;
+    first.fmt_const_new(f)?;
+    write!(f, ", ")?;
+    second.fmt_const_new(f)?;
+    write!(f, ")")
+}
+
+/// Code generator for a table of records with perfect hash function indexes.
+pub struct RecordsWriter<'a, T> {
+    records: &'a [T],
+    names: Vec<&'a str>,
+    indexes: Vec<Phf>,
+}
+
+impl<'a, T> RecordsWriter<'a, T> {
+    /// Add an index named `name` over the field of the records returned by
+    /// `key`.
+    ///
+    /// The position of the index is emitted as a constant called `name` by
+    /// [`index_constants`](RecordsWriter::index_constants), which is meant to
+    /// be used as the [`RecordKey::INDEX`] of the field at runtime.
+    ///
+    /// [`RecordKey::INDEX`]: quickphf::records::RecordKey::INDEX
+    ///
+    /// # Panics
+    ///
+    /// Panics if two records have the same key, or if an index named `name`
+    /// was already added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf_codegen::*;
+    ///
+    /// let elements = [(1, "H", "hydrogen"), (2, "He", "helium")];
+    /// let code = build_records(&elements).index("SYMBOL", |element| element.1);
+    /// ```
+    #[must_use]
+    pub fn index<K: Eq + Hash>(mut self, name: &'a str, key: impl Fn(&T) -> K) -> Self {
+        assert!(
+            !self.names.contains(&name),
+            "duplicate index name `{}`",
+            name
+        );
+
+        let keys: Vec<K> = self.records.iter().map(key).collect();
+
+        let mut first_index = HashMap::new();
+        for (idx, key) in keys.iter().enumerate() {
+            if let Some(first) = first_index.insert(key, idx) {
+                panic!(
+                    "duplicate keys in index `{}` at records {} and {}",
+                    name, first, idx
+                );
+            }
+        }
+
+        self.names.push(name);
+        self.indexes.push(generate_phf(&keys));
+        self
+    }
+
+    /// Returns a writer for the `pub const` items holding the position of
+    /// each index, named as in [`index`](RecordsWriter::index).
+    ///
+    /// Referring to these constants from the [`RecordKey`] implementations
+    /// keeps them in sync with the order in which the indexes were added.
+    ///
+    /// [`RecordKey`]: quickphf::records::RecordKey
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quickphf_codegen::*;
+    ///
+    /// let countries = [("DE", "DEU"), ("FR", "FRA")];
+    /// let records = build_records(&countries)
+    ///     .index("ISO2", |country| country.0)
+    ///     .index("ISO3", |country| country.1);
+    ///
+    /// assert_eq!(
+    ///     records.index_constants().to_string(),
+    ///     "pub const ISO2: usize = 0;\npub const ISO3: usize = 1;\n"
+    /// );
+    /// ```
+    pub fn index_constants(&self) -> IndexConstantsWriter<'_> {
+        IndexConstantsWriter { names: &self.names }
+    }
+}
+
+/// Code generator for the positions of the indexes of a [`RecordsWriter`].
+pub struct IndexConstantsWriter<'a> {
+    names: &'a [&'a str],
+}
+
+impl fmt::Display for IndexConstantsWriter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (position, name) in self.names.iter().enumerate() {
+            writeln!(f, "pub const {}: usize = {};", name, position)?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a, T: ConstInstantiable> fmt::Display for RecordsWriter<'a, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "::quickphf::PhfRecords::new(")?;
+
+        write!(f, "    &")?;
+        write_slice(self.records.iter(), f)?;
+        writeln!(f, ",")?;
+
+        // Each index maps the slots of its codomain to the positions of the
+        // records, which are kept in their original order.
+        writeln!(f, "    &[")?;
+        for index in &self.indexes {
+            write!(f, "        ::quickphf::RawPhfMap::new({}, &", index.seed)?;
+            write_slice(index.pilots_table.iter(), f)?;
+            write!(f, ", &")?;
+            write_slice(index.map.iter(), f)?;
+            write!(f, ", &")?;
+            write_slice(index.free.iter(), f)?;
+            writeln!(f, "),")?;
+        }
+        writeln!(f, "    ],")?;
+
+        write!(f, ")")
+    }
+}
+
+fn write_slice<'a, T: ConstInstantiable + 'a>(
+    entries: impl Iterator<Item = &'a T>,
+    f: &mut fmt::Formatter<'_>,
+) -> fmt::Result {
+    write!(f, "[")?;
+
+    let mut prev_entry = false;
+    for entry in entries {
+        if prev_entry {
+            write!(f, ", ")?;
+        } else {
+            prev_entry = true;
+        }
+
+        entry.fmt_const_new(f)?;
+    }
+
+    write!(f, "]")
+}
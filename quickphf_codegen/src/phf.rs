@@ -3,14 +3,7 @@
 use core::hash::Hash;
 
 use quickdiv::DivisorU64;
-use quickphf::shared::{get_bucket, get_index, hash_key, hash_pilot_value};
-
-const MAX_ALPHA: f64 = 0.99;
-const MIN_C: f64 = 1.5;
-
-fn ilog2(n: u64) -> u32 {
-    63 - n.leading_zeros()
-}
+use quickphf::shared::{get_bucket, get_index, hash_key, hash_pilot_value, table_sizes};
 
 /// Parameters for a PTHash perfect hash function.
 #[derive(Debug)]
@@ -23,41 +16,47 @@ pub struct Phf {
 
 /// Generate a perfect hash function using PTHash for the given collection of keys.
 ///
+/// Unlike the runtime construction in [`quickphf::build`], this is free to
+/// allocate scratch space, which makes it considerably faster for large
+/// tables.
+///
 /// # Panics
 ///
 /// Panics if `entries` contains a duplicate key.
 pub fn generate_phf<H: Eq + Hash>(entries: &[H]) -> Phf {
+    // This is fine since the compiler cannot handle a static map with more than
+    // a few million entries anyway.
+    let (buckets, codomain_len) =
+        table_sizes(entries.len()).expect("too many entries for a static map");
+
     if entries.is_empty() {
         return Phf {
             seed: 0,
             map: vec![],
-            // These vectors have to be non-empty so that the number of buckets and codomain
-            // length are non-zero, and thus can be used as divisors.
-            pilots_table: vec![0],
-            free: vec![0],
+            pilots_table: vec![0; buckets],
+            free: vec![0; codomain_len],
         };
     }
 
-    let n = entries.len() as u64;
-    let lg = ilog2(n) as f64;
-    let c = MIN_C + 0.2 * lg;
-    let buckets_len = DivisorU64::new(if n > 1 {
-        ((c * n as f64) / lg).ceil() as u64
-    } else {
-        1
-    });
-
-    let alpha = MAX_ALPHA - 0.001 * lg;
-    let codomain_len = DivisorU64::new({
-        let candidate = (n as f64 / alpha).ceil() as u64;
-        candidate + (1 - candidate % 2)
-    });
+    let buckets_len = DivisorU64::new(buckets as u64);
+    let codomain_len = DivisorU64::new(codomain_len as u64);
 
     (1..)
         .find_map(|n| try_generate_phf(entries, buckets_len, codomain_len, n << 32))
         .expect("failed to resolve hash collision")
 }
 
+/// An entry together with its hash and bucket for the current seed.
+struct HashedEntry {
+    idx: usize,
+    hash: u64,
+    bucket: usize,
+}
+
+/// Using a sentinel value instead of an Option allows us to avoid an expensive
+/// reallocation.
+const EMPTY: u32 = u32::MAX;
+
 fn try_generate_phf<H: Eq + Hash>(
     entries: &[H],
     buckets_len: DivisorU64,
@@ -65,12 +64,6 @@ fn try_generate_phf<H: Eq + Hash>(
     seed: u64,
 ) -> Option<Phf> {
     // We begin by hashing the entries, assigning them to buckets, and checking for collisions.
-    struct HashedEntry {
-        idx: usize,
-        hash: u64,
-        bucket: usize,
-    }
-
     let mut hashed_entries: Vec<_> = entries
         .iter()
         .enumerate()
@@ -92,14 +85,55 @@ fn try_generate_phf<H: Eq + Hash>(
             assert!(
                 entries[e0.idx] != entries[e1.idx],
                 "duplicate keys at indices {} and {}",
-                e0.idx,
-                e1.idx
+                usize::min(e0.idx, e1.idx),
+                usize::max(e0.idx, e1.idx)
             );
             return None;
         }
     }
 
-    //
+    let (pilots_table, mut map) = find_pilots(&hashed_entries, buckets_len, codomain_len)?;
+
+    // At this point `map` is a table of size `codomain_len`, but with `n` values.
+    // We need to move the items from the back into the empty slots at the
+    // front, and compute the vector `free` that will point to their new locations.
+    let extra_slots = codomain_len.get() as usize - entries.len();
+    let mut free = vec![0; extra_slots];
+
+    let mut back_idx = entries.len();
+    for front_idx in 0..entries.len() {
+        if map[front_idx] != EMPTY {
+            continue;
+        }
+
+        while map[back_idx] == EMPTY {
+            back_idx += 1;
+        }
+
+        map[front_idx] = map[back_idx];
+        free[back_idx - entries.len()] = front_idx as u32;
+        back_idx += 1;
+    }
+
+    map.truncate(entries.len());
+
+    Some(Phf {
+        seed,
+        pilots_table,
+        map,
+        free,
+    })
+}
+
+/// Searches for pilot values for the buckets of `hashed_entries`, which must be
+/// sorted by bucket, processing the buckets in order of decreasing size.
+///
+/// Returns the pilot table and the map from the codomain to entry indices.
+fn find_pilots(
+    hashed_entries: &[HashedEntry],
+    buckets_len: DivisorU64,
+    codomain_len: DivisorU64,
+) -> Option<(Vec<u16>, Vec<u32>)> {
     struct BucketData {
         idx: usize,
         start_idx: usize,
@@ -125,14 +159,7 @@ fn try_generate_phf<H: Eq + Hash>(
 
     buckets.sort_unstable_by(|b1, b2| b1.size.cmp(&b2.size).reverse());
 
-    //
     let mut pilots_table = vec![0; buckets_len.get() as usize];
-
-    // Using a sentinel value instead of an Option here allows us to avoid an expensive
-    // reallocation. This is fine since the compiler cannot handle a static map with more than
-    // a few million entries anyway.
-    assert!((entries.len() as u64) < (u32::MAX as u64));
-    const EMPTY: u32 = u32::MAX;
     let mut map = vec![EMPTY; codomain_len.get() as usize];
 
     let mut values_to_add = Vec::new();
@@ -151,7 +178,7 @@ fn try_generate_phf<H: Eq + Hash>(
             for entry in bucket_entries {
                 let destination = get_index(entry.hash, pilot_hash, codomain_len);
 
-                if map[destination as usize] != EMPTY {
+                if map[destination] != EMPTY {
                     continue 'pilots;
                 }
 
@@ -179,33 +206,5 @@ fn try_generate_phf<H: Eq + Hash>(
         }
     }
 
-    // At this point `map` is a table of size `n_prime`, but with `n` values.
-    // We need to move the items from the back into the empty slots at the
-    // front, and compute the vector `free` that will point to their new locations.
-    let extra_slots = codomain_len.get() as usize - entries.len();
-    let mut free = vec![0; extra_slots];
-
-    let mut back_idx = entries.len();
-    for front_idx in 0..entries.len() {
-        if map[front_idx] != EMPTY {
-            continue;
-        }
-
-        while map[back_idx] == EMPTY {
-            back_idx += 1;
-        }
-
-        map[front_idx] = map[back_idx];
-        free[back_idx - entries.len()] = front_idx as u32;
-        back_idx += 1;
-    }
-
-    map.truncate(entries.len());
-
-    Some(Phf {
-        seed,
-        pilots_table,
-        map,
-        free,
-    })
+    Some((pilots_table, map))
 }